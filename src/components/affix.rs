@@ -0,0 +1,185 @@
+use yew::{
+    function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref, use_state, Callback,
+    Children, Html, Properties,
+};
+
+use crate::prelude::{
+    BorderStyle, Box, Display, Duration, Opacity, Position, Radius, Size, Spacing, TimingFn, ZIndex,
+};
+use crate::utils::IntersectionWatcher;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct AffixProps {
+    #[prop_or(String::from("0"))]
+    pub offset_top: String,
+    #[prop_or(String::from(""))]
+    pub offset_bottom: String,
+    #[prop_or_default]
+    pub on_change: Callback<bool>,
+
+    #[prop_or(Size::from("100% auto"))]
+    pub size: Size,
+    #[prop_or(Spacing::from("0"))]
+    pub padding: Spacing,
+    #[prop_or(Radius::from("0"))]
+    pub radius: Radius,
+    #[prop_or(Spacing::from("0"))]
+    pub border_width: Spacing,
+    #[prop_or(String::from("transparent"))]
+    pub border_color: String,
+    #[prop_or(BorderStyle::No)]
+    pub border_style: BorderStyle,
+    #[prop_or(String::from("transparent"))]
+    pub bg_color: String,
+    #[prop_or(String::from("none"))]
+    pub shadow: String,
+    #[prop_or(String::from("#181818"))]
+    pub color: String,
+    #[prop_or(ZIndex::from("10"))]
+    pub z_index: ZIndex,
+    #[prop_or(Opacity::from("inherit"))]
+    pub opacity: Opacity,
+    #[prop_or(Duration::from("0.2"))]
+    pub duration: Duration,
+    #[prop_or(TimingFn::Ease)]
+    pub timing_fn: TimingFn,
+
+    #[prop_or_default]
+    pub s_padding: Spacing,
+    #[prop_or_default]
+    pub s_radius: Radius,
+    #[prop_or_default]
+    pub s_border_width: Spacing,
+    #[prop_or(String::from(""))]
+    pub s_border_color: String,
+    #[prop_or(String::from(""))]
+    pub s_bg_color: String,
+    #[prop_or(String::from(""))]
+    pub s_shadow: String,
+    #[prop_or(String::from(""))]
+    pub s_color: String,
+    #[prop_or_default]
+    pub s_opacity: Opacity,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// offset_top: String, // 距离顶部多少时吸顶 "0"
+/// offset_bottom: String, // 距离底部多少时吸底，设置后 offset_top 无效
+/// on_change: Callback<bool>, // 吸顶（吸底）状态改变时触发
+/// size: Size,
+/// padding: Spacing,
+/// radius: Radius,
+/// border_width: Spacing,
+/// border_color: String,
+/// border_style: BorderStyle,
+/// bg_color: String,
+/// shadow: String,
+/// color: String,
+/// z_index: ZIndex,
+/// opacity: Opacity,
+/// duration: Duration, // 吸顶样式切换的 transition 时间 s
+/// timing_fn: TimingFn,
+/// s_padding: Spacing, // 吸顶时的样式
+/// s_radius: Radius, // 吸顶时的样式
+/// s_border_width: Spacing, // 吸顶时的样式
+/// s_border_color: String, // 吸顶时的样式
+/// s_bg_color: String, // 吸顶时的样式
+/// s_shadow: String, // 吸顶时的样式 "0 2px 8px rgba(0, 0, 0, 0.15)"
+/// s_color: String, // 吸顶时的样式
+/// s_opacity: Opacity, // 吸顶时的样式
+///```
+#[function_component]
+pub fn Affix(props: &AffixProps) -> Html {
+    let node = use_node_ref();
+    let stuck = use_state(|| false);
+    let last_stuck = use_mut_ref(|| false);
+    // 每次渲染更新，滚动监听中使用最新的回调
+    let on_change = use_mut_ref(Callback::<bool>::default);
+    *on_change.borrow_mut() = props.on_change.clone();
+
+    {
+        let node = node.clone();
+        let stuck = stuck.clone();
+        let last_stuck = last_stuck.clone();
+        let on_change = on_change.clone();
+        use_effect_with_deps(
+            move |(offset_top, offset_bottom)| {
+                let is_bottom = offset_bottom != &String::default();
+                let offset = if is_bottom {
+                    offset_bottom.parse::<f64>().unwrap_or(0.0)
+                } else {
+                    offset_top.parse::<f64>().unwrap_or(0.0)
+                };
+                // 元素贴住边缘时，会有 1px 超出 root 范围，以此判断是否吸顶
+                let root_margin = if is_bottom {
+                    format!("0px 0px {}px 0px", -(offset + 1.0))
+                } else {
+                    format!("{}px 0px 0px 0px", -(offset + 1.0))
+                };
+                let watcher = node.cast::<web_sys::Element>().map(|el| {
+                    IntersectionWatcher::new(&el, &root_margin, 1.0, move |entry| {
+                        let rect = entry.bounding_client_rect();
+                        let is_stuck = if entry.intersection_ratio() >= 1.0 {
+                            false
+                        } else if is_bottom {
+                            let height = web_sys::window()
+                                .and_then(|w| w.inner_height().ok())
+                                .and_then(|h| h.as_f64())
+                                .unwrap_or(0.0);
+                            rect.bottom() >= height - offset - 1.0 && rect.top() < height
+                        } else {
+                            rect.top() <= offset + 1.0 && rect.bottom() > 0.0
+                        };
+                        if *last_stuck.borrow() != is_stuck {
+                            *last_stuck.borrow_mut() = is_stuck;
+                            stuck.set(is_stuck);
+                            on_change.borrow().emit(is_stuck);
+                        }
+                    })
+                });
+                move || drop(watcher)
+            },
+            (props.offset_top.clone(), props.offset_bottom.clone()),
+        );
+    }
+
+    let is_bottom = props.offset_bottom != String::default();
+
+    html! {
+        <Box
+            node={node}
+            display={Display::Block}
+            position={Position::Sticky}
+            top={if is_bottom { String::from("auto") } else { props.offset_top.clone() }}
+            bottom={if is_bottom { props.offset_bottom.clone() } else { String::from("auto") }}
+            size={props.size.clone()}
+            padding={pick_stuck(*stuck, &props.s_padding, &props.padding)}
+            radius={pick_stuck(*stuck, &props.s_radius, &props.radius)}
+            border_width={pick_stuck(*stuck, &props.s_border_width, &props.border_width)}
+            border_color={pick_stuck(*stuck, &props.s_border_color, &props.border_color)}
+            border_style={props.border_style.clone()}
+            bg_color={pick_stuck(*stuck, &props.s_bg_color, &props.bg_color)}
+            shadow={pick_stuck(*stuck, &props.s_shadow, &props.shadow)}
+            color={pick_stuck(*stuck, &props.s_color, &props.color)}
+            z_index={props.z_index.clone()}
+            opacity={pick_stuck(*stuck, &props.s_opacity, &props.opacity)}
+            duration={props.duration.clone()}
+            timing_fn={props.timing_fn.clone()}
+        >
+        { for props.children.iter() }
+        </Box>
+    }
+}
+
+/// 吸顶时使用设置了的吸顶样式，字符串为空、Size 等类型未设置时沿用普通样式
+fn pick_stuck<T: Clone + Default + PartialEq>(stuck: bool, s_value: &T, value: &T) -> T {
+    if stuck && s_value != &T::default() {
+        s_value.clone()
+    } else {
+        value.clone()
+    }
+}
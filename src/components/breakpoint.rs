@@ -0,0 +1,173 @@
+use yew::{
+    function_component, hook, html, use_context, use_effect_with_deps, use_state_eq, Children,
+    ContextProvider, Html, Properties,
+};
+
+use crate::utils::{media_matches, MediaQueryWatcher};
+
+/// 断点表，按 min-width 从小到大排列
+/// 在支持响应式的属性中使用，如 size="100% md:50% lg:33%"
+/// containers 为容器查询的断点，如 size="100% @md:50%"，指定容器名称 "@md/card:50%"
+#[derive(Clone, PartialEq)]
+pub struct Breakpoints {
+    pub points: Vec<(String, String)>,
+    pub containers: Vec<(String, String)>,
+}
+impl Breakpoints {
+    /// Breakpoints::new(vec![("pad", "600px"), ("pc", "1200px")])
+    pub fn new(points: Vec<(&str, &str)>) -> Self {
+        Breakpoints {
+            points: to_points(points),
+            containers: Breakpoints::default().containers,
+        }
+    }
+
+    /// Breakpoints::default().with_containers(vec![("small", "300px"), ("large", "600px")])
+    pub fn with_containers(mut self, containers: Vec<(&str, &str)>) -> Self {
+        self.containers = to_points(containers);
+        self
+    }
+
+    pub fn get_names(&self) -> Vec<String> {
+        self.points.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// 视口断点的宽度，"md" => "768px"
+    pub fn get_width(&self, name: &str) -> Option<String> {
+        self.points
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, width)| width.clone())
+    }
+
+    /// "md" => "@media (min-width: 768px)"
+    /// "@md" => "@container (min-width: 448px)"
+    /// "@md/card" => "@container card (min-width: 448px)"
+    pub fn get_query(&self, name: &str) -> Option<String> {
+        match name.strip_prefix("@") {
+            Some(container) => {
+                let (point, container_name) = match container.split_once("/") {
+                    Some((point, container_name)) => (point, container_name.to_owned() + " "),
+                    None => (container, String::default()),
+                };
+                self.containers
+                    .iter()
+                    .find(|(n, _)| n == point)
+                    .map(|(_, width)| {
+                        format!("@container {}(min-width: {})", container_name, width)
+                    })
+            }
+            None => self
+                .get_width(name)
+                .map(|width| format!("@media (min-width: {})", width)),
+        }
+    }
+
+    /// 断点的先后顺序，媒体查询在前，容器查询在后
+    pub fn get_order(&self, name: &str) -> usize {
+        match name.strip_prefix("@") {
+            Some(container) => {
+                let point = container.split("/").next().unwrap_or_default();
+                self.points.len()
+                    + self
+                        .containers
+                        .iter()
+                        .position(|(n, _)| n == point)
+                        .unwrap_or_default()
+            }
+            None => self
+                .points
+                .iter()
+                .position(|(n, _)| n == name)
+                .unwrap_or_default(),
+        }
+    }
+}
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Breakpoints {
+            points: to_points(vec![
+                ("sm", "640px"),
+                ("md", "768px"),
+                ("lg", "1024px"),
+                ("xl", "1280px"),
+                ("xxl", "1536px"),
+            ]),
+            containers: to_points(vec![
+                ("xs", "320px"),
+                ("sm", "384px"),
+                ("md", "448px"),
+                ("lg", "512px"),
+                ("xl", "576px"),
+                ("xxl", "672px"),
+            ]),
+        }
+    }
+}
+
+fn to_points(points: Vec<(&str, &str)>) -> Vec<(String, String)> {
+    points
+        .iter()
+        .map(|(name, width)| (name.to_string(), width.to_string()))
+        .collect()
+}
+
+/// 获取当前的断点表，没有 BreakpointProvider 时为默认值
+#[hook]
+pub fn use_breakpoints() -> Breakpoints {
+    use_context::<Breakpoints>().unwrap_or_default()
+}
+
+/// 是否匹配媒体查询，匹配结果改变时更新，如 use_media_query("(hover: hover)")
+/// SSR 时为 false
+#[hook]
+pub fn use_media_query(query: &str) -> bool {
+    let matches = use_state_eq(|| media_matches(query));
+    {
+        let matches = matches.clone();
+        use_effect_with_deps(
+            move |query| {
+                let watcher = MediaQueryWatcher::new(query, {
+                    let matches = matches.clone();
+                    move |x| matches.set(x)
+                });
+                matches.set(watcher.as_ref().map(|x| x.matches()).unwrap_or(false));
+                move || drop(watcher)
+            },
+            query.to_owned(),
+        );
+    }
+    *matches
+}
+
+/// 视口宽度是否达到断点，如 use_breakpoint("md")，断点来自 BreakpointProvider
+#[hook]
+pub fn use_breakpoint(name: &str) -> bool {
+    let query = use_breakpoints()
+        .get_width(name)
+        .map(|width| format!("(min-width: {})", width))
+        .unwrap_or_else(|| String::from("not all"));
+    use_media_query(&query)
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct BreakpointProviderProps {
+    #[prop_or_default]
+    pub breakpoints: Breakpoints,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// breakpoints: Breakpoints, // 默认 sm 640px, md 768px, lg 1024px, xl 1280px, xxl 1536px
+///                           // 容器查询默认 @xs 320px, @sm 384px, @md 448px, @lg 512px, @xl 576px, @xxl 672px
+///```
+#[function_component]
+pub fn BreakpointProvider(props: &BreakpointProviderProps) -> Html {
+    html! {
+        <ContextProvider<Breakpoints> context={props.breakpoints.clone()}>
+        { for props.children.iter() }
+        </ContextProvider<Breakpoints>>
+    }
+}
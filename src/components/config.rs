@@ -0,0 +1,264 @@
+use std::rc::Rc;
+
+use yew::{
+    function_component, hook, html, props, use_context, Children, ContextProvider, Html, Properties,
+};
+
+use crate::prelude::{
+    BoxProps, FlexProps, FlexWay, ImageProps, LineProps, TextEllipsisProps, TextProps, Theme,
+};
+use crate::utils::{report, split_values};
+
+/// 组件的默认属性，在组件中未设置的属性使用这里的值
+#[derive(Clone, PartialEq, Default)]
+pub struct Config {
+    pub(crate) box_props: Option<Rc<BoxProps>>,
+    pub(crate) flex_props: Option<Rc<FlexProps>>,
+    pub(crate) text_props: Option<Rc<TextProps>>,
+    pub(crate) text_ellipsis_props: Option<Rc<TextEllipsisProps>>,
+    pub(crate) image_props: Option<Rc<ImageProps>>,
+    pub(crate) line_props: Option<Rc<LineProps>>,
+    pub(crate) theme: Option<Rc<Theme>>,
+    pub(crate) presets: Vec<(String, String)>,
+}
+impl Config {
+    /// Config::default().with_box(|p| p.display = Some(Display::Block))
+    pub fn with_box(mut self, f: impl FnOnce(&mut BoxProps)) -> Self {
+        let mut p = default_box_props();
+        f(&mut p);
+        self.box_props = Some(Rc::new(p));
+        self
+    }
+
+    /// flex 为必填属性，不能修改默认值
+    pub fn with_flex(mut self, f: impl FnOnce(&mut FlexProps)) -> Self {
+        let mut p = default_flex_props();
+        f(&mut p);
+        self.flex_props = Some(Rc::new(p));
+        self
+    }
+
+    /// Config::default().with_text(|p| { p.font_size = Some("14".into()); p.color = Some("#333".into()); })
+    pub fn with_text(mut self, f: impl FnOnce(&mut TextProps)) -> Self {
+        let mut p = default_text_props();
+        f(&mut p);
+        self.text_props = Some(Rc::new(p));
+        self
+    }
+
+    /// Config::default().with_text_ellipsis(|p| p.line = Some("2".into()))
+    pub fn with_text_ellipsis(mut self, f: impl FnOnce(&mut TextEllipsisProps)) -> Self {
+        let mut p = default_text_ellipsis_props();
+        f(&mut p);
+        self.text_ellipsis_props = Some(Rc::new(p));
+        self
+    }
+
+    /// src 为必填属性，不能修改默认值
+    pub fn with_image(mut self, f: impl FnOnce(&mut ImageProps)) -> Self {
+        let mut p = default_image_props();
+        f(&mut p);
+        self.image_props = Some(Rc::new(p));
+        self
+    }
+
+    pub fn with_line(mut self, f: impl FnOnce(&mut LineProps)) -> Self {
+        let mut p = default_line_props();
+        f(&mut p);
+        self.line_props = Some(Rc::new(p));
+        self
+    }
+
+    /// sx 工具类使用的主题
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(Rc::new(theme));
+        self
+    }
+
+    pub fn get_theme(&self) -> Rc<Theme> {
+        self.theme.clone().unwrap_or_default()
+    }
+
+    /// 命名的样式预设，值为 sx 工具类，其中可以使用其他预设的名称
+    /// Config::default()
+    ///     .with_preset("card", "p-4 rounded-lg bg-white shadow-md dark:bg-gray-800")
+    ///     .with_preset("panel", "card border border-gray-200")
+    pub fn with_preset(mut self, name: &str, sx: &str) -> Self {
+        self.presets.retain(|(n, _)| n != name);
+        self.presets.push((name.to_owned(), sx.to_owned()));
+        self
+    }
+
+    /// 将预设名称展开为 sx 工具类，"card" => "p-4 rounded-lg bg-white shadow-md dark:bg-gray-800"
+    pub fn expand_presets(&self, names: &str) -> String {
+        let mut stack = vec![];
+        self.expand_names(names, &mut stack)
+    }
+
+    fn expand_names(&self, names: &str, stack: &mut Vec<String>) -> String {
+        let mut list = vec![];
+        for name in split_values(names) {
+            if stack.contains(&name) {
+                report(&format!(
+                    "yew_quick preset: circular preset {} -> {}",
+                    stack.join(" -> "),
+                    name
+                ));
+                continue;
+            }
+            match self.presets.iter().find(|(n, _)| n == &name) {
+                Some((_, sx)) => {
+                    stack.push(name);
+                    list.push(self.expand_sx(sx, stack));
+                    stack.pop();
+                }
+                None => {
+                    if cfg!(debug_assertions) {
+                        report(&format!("yew_quick preset: unknown preset {:?}", name));
+                    }
+                }
+            }
+        }
+        list.join(" ")
+    }
+
+    /// 预设中的工具类原样保留，预设名称继续展开
+    fn expand_sx(&self, sx: &str, stack: &mut Vec<String>) -> String {
+        split_values(sx)
+            .iter()
+            .map(|x| {
+                if self.presets.iter().any(|(n, _)| n == x) {
+                    self.expand_names(x, stack)
+                } else {
+                    x.clone()
+                }
+            })
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// 未配置的组件沿用 parent 的配置，预设合并，同名时以当前的为准
+    fn merge(&self, parent: &Config) -> Config {
+        Config {
+            box_props: self.box_props.clone().or(parent.box_props.clone()),
+            flex_props: self.flex_props.clone().or(parent.flex_props.clone()),
+            text_props: self.text_props.clone().or(parent.text_props.clone()),
+            text_ellipsis_props: self
+                .text_ellipsis_props
+                .clone()
+                .or(parent.text_ellipsis_props.clone()),
+            image_props: self.image_props.clone().or(parent.image_props.clone()),
+            line_props: self.line_props.clone().or(parent.line_props.clone()),
+            theme: self.theme.clone().or(parent.theme.clone()),
+            presets: parent
+                .presets
+                .iter()
+                .filter(|(name, _)| self.presets.iter().all(|(n, _)| n != name))
+                .chain(self.presets.iter())
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// 各组件的内置默认属性，必填属性填入占位值
+pub(crate) fn default_box_props() -> BoxProps {
+    props!(BoxProps {})
+}
+
+pub(crate) fn default_flex_props() -> FlexProps {
+    props!(FlexProps {
+        flex: FlexWay::Fraa
+    })
+}
+
+pub(crate) fn default_text_props() -> TextProps {
+    props!(TextProps {})
+}
+
+pub(crate) fn default_text_ellipsis_props() -> TextEllipsisProps {
+    props!(TextEllipsisProps {})
+}
+
+pub(crate) fn default_image_props() -> ImageProps {
+    props!(ImageProps { src: String::new() })
+}
+
+pub(crate) fn default_line_props() -> LineProps {
+    props!(LineProps {})
+}
+
+/// 获取当前的默认属性配置，没有 ConfigProvider 时为空
+#[hook]
+pub fn use_config() -> Config {
+    use_context::<Config>().unwrap_or_default()
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ConfigProviderProps {
+    #[prop_or_default]
+    pub config: Config,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// 嵌套时，未配置的组件沿用外层的配置
+/// ### 使用示例
+///```text
+/// config: Config, // Config::default()
+///                 //     .with_box(|p| p.display = Some(Display::Block))
+///                 //     .with_text(|p| { p.font_size = Some("14".into()); p.color = Some("#333".into()); })
+///                 //     .with_theme(Theme::default()) // sx 工具类使用的主题
+///                 //     .with_preset("card", "p-4 rounded-lg shadow-md") // 样式预设，组件中 preset="card"
+///                 // 组件中未设置的属性，使用这里的值
+///```
+#[function_component]
+pub fn ConfigProvider(props: &ConfigProviderProps) -> Html {
+    let parent = use_config();
+    html! {
+        <ContextProvider<Config> context={props.config.merge(&parent)}>
+        { for props.children.iter() }
+        </ContextProvider<Config>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::box_css;
+
+    fn config() -> Config {
+        Config::default()
+            .with_preset("card", "p-4 rounded-lg")
+            .with_preset("panel", "card border")
+            .with_preset("a", "b m-1")
+            .with_preset("b", "a m-2")
+    }
+
+    #[test]
+    fn chained_presets() {
+        assert_eq!(config().expand_presets("card"), "p-4 rounded-lg");
+        assert_eq!(config().expand_presets("panel"), "p-4 rounded-lg border");
+        assert_eq!(
+            config().expand_presets("panel unknown"),
+            "p-4 rounded-lg border"
+        );
+    }
+
+    #[test]
+    fn circular_presets() {
+        assert_eq!(config().expand_presets("a"), "m-2 m-1");
+        assert_eq!(config().expand_presets("b"), "m-1 m-2");
+    }
+
+    #[test]
+    fn explicit_props_win_over_presets() {
+        let sx = config().expand_presets("card");
+        let css = box_css(&props!(BoxProps { sx: sx.clone() }));
+        assert!(css.contains("padding: 16px 16px 16px 16px;"), "{css}");
+        let css = box_css(&props!(BoxProps { sx, padding: "0" }));
+        assert!(css.contains("padding: 0px;"), "{css}");
+        assert!(!css.contains("16px"), "{css}");
+    }
+}
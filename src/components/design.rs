@@ -0,0 +1,171 @@
+use yew::{
+    function_component, hook, html, use_context, Children, ContextProvider, Html, Properties,
+};
+
+use stylist::yew::Global;
+
+use crate::prelude::DesignUnit;
+use crate::utils::split_values;
+
+/// 设计稿尺寸换算，"20rpx" 按设计稿宽度换算为 vw 或 rem
+/// unitless 为 true 时，无单位的数字也按 rpx 换算
+#[derive(Clone, PartialEq)]
+pub struct Design {
+    pub width: f64,
+    pub unit: DesignUnit,
+    pub root_size: f64,
+    pub unitless: bool,
+}
+impl Design {
+    /// Design::new(750.0)，750rpx 为屏幕宽度
+    pub fn new(width: f64) -> Self {
+        Design {
+            width,
+            unit: DesignUnit::Vw,
+            root_size: width / 10.0,
+            unitless: false,
+        }
+    }
+
+    /// 换算为 rem，root_size 为 1rem 对应的设计稿尺寸，如 Design::new(750.0).with_rem(75.0)
+    pub fn with_rem(mut self, root_size: f64) -> Self {
+        self.unit = DesignUnit::Rem;
+        self.root_size = root_size;
+        self
+    }
+
+    /// 无单位的数字也按 rpx 换算
+    pub fn with_unitless(mut self, unitless: bool) -> Self {
+        self.unitless = unitless;
+        self
+    }
+
+    /// "10rpx 20 md:30rpx" => "1.3333vw 20 md:4vw"
+    pub fn convert(&self, data: &str) -> String {
+        if !self.unitless && !data.contains("rpx") {
+            return data.to_owned();
+        }
+        split_values(data)
+            .iter()
+            .map(|x| match x.split_once(":") {
+                Some((name, value)) => format!("{}:{}", name, self.convert_value(value)),
+                None => self.convert_value(x),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn convert_value(&self, value: &str) -> String {
+        if value.contains("(") {
+            return self.convert_args(value);
+        }
+        let number = match value.strip_suffix("rpx") {
+            Some(number) => number.parse::<f64>().ok(),
+            None if self.unitless => value.parse::<f64>().ok(),
+            // 与 rpx 混用时，其余无单位的数字仍为 px，如 "20rpx 10"
+            None => {
+                return match value.parse::<f64>() {
+                    Ok(number) if number != 0.0 => format!("{}px", number),
+                    _ => value.to_owned(),
+                }
+            }
+        };
+        match number {
+            Some(0.0) => "0".to_owned(),
+            Some(number) => self.to_unit(number),
+            None => value.to_owned(),
+        }
+    }
+
+    /// 函数参数中只换算 rpx，"calc(100%-20rpx)" => "calc(100%-2.6667vw)"
+    fn convert_args(&self, value: &str) -> String {
+        let chars = value.chars().collect::<Vec<char>>();
+        let is_word = |c: char| c.is_ascii_alphanumeric() || c == '.' || c == '_';
+        let mut result = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if !(c.is_ascii_digit() || c == '.') || (i > 0 && is_word(chars[i - 1])) {
+                result.push(c);
+                i += 1;
+                continue;
+            }
+            let mut j = i;
+            while j < chars.len() && (chars[j].is_ascii_digit() || chars[j] == '.') {
+                j += 1;
+            }
+            let number = chars[i..j].iter().collect::<String>();
+            let is_rpx = chars[j..].starts_with(&['r', 'p', 'x'])
+                && !chars.get(j + 3).map(|c| is_word(*c)).unwrap_or(false);
+            match number.parse::<f64>() {
+                // calc 中的 0 需要带单位
+                Ok(number) if is_rpx && number == 0.0 => result += "0px",
+                Ok(number) if is_rpx => result += &self.to_unit(number),
+                _ => {
+                    result += &number;
+                    i = j;
+                    continue;
+                }
+            }
+            i = j + 3;
+        }
+        result
+    }
+
+    fn to_unit(&self, number: f64) -> String {
+        match self.unit {
+            DesignUnit::Vw => format!("{}vw", round(number * 100.0 / self.width)),
+            DesignUnit::Rem => format!("{}rem", round(number / self.root_size)),
+        }
+    }
+
+    /// rem 模式下 html 的 font-size
+    pub fn root_font_size(&self) -> String {
+        format!("{}vw", round(self.root_size * 100.0 / self.width))
+    }
+}
+impl Default for Design {
+    fn default() -> Self {
+        Design::new(750.0)
+    }
+}
+
+fn round(number: f64) -> f64 {
+    (number * 10000.0).round() / 10000.0
+}
+
+/// 获取当前的设计稿换算，没有 DesignProvider 时为默认值（750，只换算 rpx）
+#[hook]
+pub fn use_design() -> Design {
+    use_context::<Design>().unwrap_or_default()
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct DesignProviderProps {
+    #[prop_or_default]
+    pub design: Design,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// design: Design, // 默认 Design::new(750.0)，换算为 vw，只换算带 rpx 的值
+///                 // Design::new(750.0).with_unitless(true) 无单位的数字也换算
+///                 // Design::new(750.0).with_rem(75.0) 换算为 rem，同时设置 html 的 font-size
+///```
+#[function_component]
+pub fn DesignProvider(props: &DesignProviderProps) -> Html {
+    let global = match props.design.unit {
+        DesignUnit::Rem => html! {
+            <Global css={format!("html {{ font-size: {}; }}", props.design.root_font_size())} />
+        },
+        DesignUnit::Vw => html! {},
+    };
+    html! {
+        <ContextProvider<Design> context={props.design.clone()}>
+        { global }
+        { for props.children.iter() }
+        </ContextProvider<Design>>
+    }
+}
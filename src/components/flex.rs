@@ -2,13 +2,16 @@ use yew::{
     function_component, html, use_memo, Callback, Children, Html, MouseEvent, NodeRef, Properties,
 };
 
-use stylist::css;
-
 use crate::prelude::{
-    BorderStyle, BoxSizing, Cursor, FlexWay, FontStyle, FontWeight, ImageMode, Overflow, Position,
-    TextAlign, TimingFn, WhiteSpace, WordBreak,
+    use_breakpoints, use_config, use_design, BorderStyle, BoxSizing, Breakpoints, ContainerType,
+    Cursor, Design, Duration, FlexWay, FontStyle, FontWeight, ImageMode, Opacity, Overflow,
+    Position, Radius, SafeMode, SafeType, Size, Spacing, TextAlign, Theme, TimingFn, WhiteSpace,
+    WordBreak, ZIndex,
+};
+use crate::utils::{
+    add_op_space, design_props, is_have_unit, resolved_props, responsive_css, responsive_props,
+    safe_area_value, style_source, sx_props, CssBlocks, SxStyle,
 };
-use crate::utils::{add_op_space, is_have_unit};
 
 #[derive(Clone, PartialEq)]
 struct FlexCss {
@@ -16,6 +19,10 @@ struct FlexCss {
     height: String,
     padding: String,
     margin: String,
+    safe_padding: String,
+    safe_padding_constant: String,
+    safe_margin: String,
+    safe_margin_constant: String,
     box_sizing: String,
     border_radius: String,
     border_width: String,
@@ -23,6 +30,8 @@ struct FlexCss {
     border_style: String,
     background_color: String,
     overflow: String,
+    container_type: String,
+    container_name: String,
     background_image: String,
     background_size: String,
     backdrop_filter: String,
@@ -77,12 +86,96 @@ struct FlexCss {
     dark_color: String,
 }
 
+impl FlexCss {
+    fn to_blocks(&self) -> CssBlocks {
+        CssBlocks {
+            base: vec![
+                ("display", "flex".to_owned()),
+                ("flex-direction", self.flex_direction.clone()),
+                ("justify-content", self.justify_content.clone()),
+                ("align-items", self.align_items.clone()),
+                ("width", self.width.clone()),
+                ("height", self.height.clone()),
+                ("padding", self.padding.clone()),
+                ("padding", self.safe_padding_constant.clone()),
+                ("padding", self.safe_padding.clone()),
+                ("margin", self.margin.clone()),
+                ("margin", self.safe_margin_constant.clone()),
+                ("margin", self.safe_margin.clone()),
+                ("box-sizing", self.box_sizing.clone()),
+                ("border-radius", self.border_radius.clone()),
+                ("border-width", self.border_width.clone()),
+                ("border-color", self.border_color.clone()),
+                ("border-style", self.border_style.clone()),
+                ("background-color", self.background_color.clone()),
+                ("overflow", self.overflow.clone()),
+                ("container-type", self.container_type.clone()),
+                ("container-name", self.container_name.clone()),
+                ("background-image", self.background_image.clone()),
+                ("background-repeat", "no-repeat".to_owned()),
+                ("background-position", "center".to_owned()),
+                ("background-size", self.background_size.clone()),
+                ("backdrop-filter", self.backdrop_filter.clone()),
+                ("cursor", self.cursor.clone()),
+                ("white-space", self.white_space.clone()),
+                ("min-width", self.min_width.clone()),
+                ("min-height", self.min_height.clone()),
+                ("max-width", self.max_width.clone()),
+                ("max-height", self.max_height.clone()),
+                ("box-shadow", self.box_shadow.clone()),
+                ("position", self.position.clone()),
+                ("left", self.left.clone()),
+                ("top", self.top.clone()),
+                ("right", self.right.clone()),
+                ("bottom", self.bottom.clone()),
+                ("z-index", self.z_index.clone()),
+                ("opacity", self.opacity.clone()),
+                ("font-size", self.font_size.clone()),
+                ("color", self.color.clone()),
+                ("font-style", self.font_style.clone()),
+                ("font-weight", self.font_weight.clone()),
+                ("letter-spacing", self.letter_spacing.clone()),
+                ("line-height", self.line_height.clone()),
+                ("text-decoration", self.text_decoration.clone()),
+                ("text-align", self.text_align.clone()),
+                ("word-break", self.word_break.clone()),
+                ("flex-shrink", self.flex_shrink.clone()),
+                (
+                    "transition",
+                    format!("all {}s {}", self.duration, self.timing_fn),
+                ),
+            ],
+            hover: vec![
+                ("background-color", self.hover_bg_color.clone()),
+                ("color", self.hover_color.clone()),
+                ("box-shadow", self.hover_shadow.clone()),
+                ("width", self.hover_width.clone()),
+                ("height", self.hover_height.clone()),
+                ("padding", self.hover_padding.clone()),
+                ("margin", self.hover_margin.clone()),
+                ("border-radius", self.hover_radius.clone()),
+                ("border-width", self.hover_border_width.clone()),
+                ("border-color", self.hover_border_color.clone()),
+                ("opacity", self.hover_opacity.clone()),
+            ],
+            dark: vec![
+                ("background-color", self.dark_bg_color.clone()),
+                ("color", self.dark_color.clone()),
+                ("box-shadow", self.dark_shadow.clone()),
+                ("border-color", self.dark_border_color.clone()),
+            ],
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct FlexCssProps {
     flex: FlexWay,
     size: String,
     padding: String,
     margin: String,
+    safe: SafeType,
+    safe_mode: SafeMode,
     box_sizing: BoxSizing,
     radius: String,
     border_width: String,
@@ -92,6 +185,8 @@ struct FlexCssProps {
     bg_image: String,
     image_mode: ImageMode,
     overflow: Overflow,
+    container_type: ContainerType,
+    container_name: String,
     backdrop: String,
     cursor: Cursor,
     white_space: WhiteSpace,
@@ -137,115 +232,593 @@ struct FlexCssProps {
     d_color: String,
 }
 
+fn get_flex_css(box_css_p: &FlexCssProps) -> FlexCss {
+    let f_vec: Vec<String> = box_css_p
+        .flex
+        .get_name()
+        .split("")
+        .filter(|x| x != &String::default() && x != &"f")
+        .map(|x| match x {
+            "r" => "row".to_string(),
+            "c" => "center".to_string(),
+            "b" => "space-between".to_string(),
+            "a" => "space-around".to_string(),
+            "e" => "flex-end".to_string(),
+            "s" => "flex-start".to_string(),
+            _ => "center".to_string(),
+        })
+        .collect();
+    let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
+    let temp_h_size = if box_css_p.h_size == String::default() {
+        temp_size.clone()
+    } else {
+        box_css_p.h_size.split(" ").collect::<Vec<&str>>()
+    };
+    let temp_min_size = box_css_p.min_size.split(" ").collect::<Vec<&str>>();
+    let temp_max_size = box_css_p.max_size.split(" ").collect::<Vec<&str>>();
+
+    let temp_width = temp_size[0];
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+
+    let temp_h_width = temp_h_size[0];
+    let temp_h_height = if temp_h_size.len() == 1 {
+        temp_h_size[0]
+    } else {
+        temp_h_size[1]
+    };
+
+    let temp_min_width = temp_min_size[0];
+    let temp_min_height = if temp_min_size.len() == 1 {
+        temp_min_size[0]
+    } else {
+        temp_min_size[1]
+    };
+    let temp_max_width = temp_max_size[0];
+    let temp_max_height = if temp_max_size.len() == 1 {
+        temp_max_size[0]
+    } else {
+        temp_max_size[1]
+    };
+
+    let temp_width_op = add_op_space(temp_width);
+    let tmep_width_f = if temp_width.contains("%") {
+        temp_width.to_string()
+    } else {
+        format!("{}{}", temp_width, "px")
+    };
+    let temp_height_op = add_op_space(temp_height);
+    let temp_height_f = if temp_height.contains("%") {
+        temp_height.to_string()
+    } else {
+        format!("{}{}", temp_height, "px")
+    };
+
+    let temp_h_width_op = add_op_space(temp_h_width);
+    let tmep_h_width_f = if temp_h_width.contains("%") {
+        temp_h_width.to_string()
+    } else {
+        format!("{}{}", temp_h_width, "px")
+    };
+    let temp_h_height_op = add_op_space(temp_h_height);
+    let temp_h_height_f = if temp_h_height.contains("%") {
+        temp_h_height.to_string()
+    } else {
+        format!("{}{}", temp_h_height, "px")
+    };
+
+    let temp_min_width_op = add_op_space(temp_min_width);
+    let tmep_min_width_f = if temp_min_width.contains("%") {
+        temp_min_width.to_string()
+    } else {
+        format!("{}{}", temp_min_width, "px")
+    };
+    let temp_min_height_op = add_op_space(temp_min_height);
+    let temp_min_height_f = if temp_min_height.contains("%") {
+        temp_min_height.to_string()
+    } else {
+        format!("{}{}", temp_min_height, "px")
+    };
+    let temp_max_width_op = add_op_space(temp_max_width);
+    let tmep_max_width_f = if temp_max_width.contains("%") {
+        temp_max_width.to_string()
+    } else {
+        format!("{}{}", temp_max_width, "px")
+    };
+    let temp_max_height_op = add_op_space(temp_max_height);
+    let temp_max_height_f = if temp_max_height.contains("%") {
+        temp_max_height.to_string()
+    } else {
+        format!("{}{}", temp_max_height, "px")
+    };
+
+    let temp_padding = box_css_p.padding.as_str();
+    let temp_margin = box_css_p.margin.as_str();
+    let temp_radius = box_css_p.radius.as_str();
+    let temp_border_width = box_css_p.border_width.as_str();
+
+    let temp_padding_c = temp_padding
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let padding_value = if is_have_unit(temp_padding) {
+        temp_padding.to_owned()
+    } else {
+        temp_padding_c
+    };
+    let temp_margin_c = temp_margin
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let margin_value = if is_have_unit(temp_margin) {
+        temp_margin.to_owned()
+    } else {
+        temp_margin_c
+    };
+    let temp_radius_c = temp_radius
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || x.contains("/") {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let radius_value = if is_have_unit(temp_radius) {
+        temp_radius.to_owned()
+    } else {
+        temp_radius_c
+    };
+    let temp_border_width_c = temp_border_width
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let border_width_value = if is_have_unit(temp_border_width) {
+        temp_border_width.to_owned()
+    } else {
+        temp_border_width_c
+    };
+    let (padding_sides, margin_sides) = match box_css_p.safe_mode {
+        SafeMode::Padding => (box_css_p.safe.get_sides(), SafeType::None.get_sides()),
+        SafeMode::Margin => (SafeType::None.get_sides(), box_css_p.safe.get_sides()),
+    };
+    FlexCss {
+        flex_direction: if f_vec[0] == "center" {
+            "column".to_string()
+        } else {
+            f_vec[0].clone()
+        },
+        justify_content: f_vec[1].clone(),
+        align_items: f_vec[2].clone(),
+        width: if temp_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_width) {
+            temp_width_op
+        } else {
+            tmep_width_f
+        },
+        height: if temp_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_height) {
+            temp_height_op
+        } else {
+            temp_height_f
+        },
+        padding: padding_value.clone(),
+        margin: margin_value.clone(),
+        safe_padding: safe_area_value(&padding_value, padding_sides, "env"),
+        safe_padding_constant: safe_area_value(&padding_value, padding_sides, "constant"),
+        safe_margin: safe_area_value(&margin_value, margin_sides, "env"),
+        safe_margin_constant: safe_area_value(&margin_value, margin_sides, "constant"),
+        box_sizing: box_css_p.box_sizing.get_name(),
+        border_radius: radius_value.clone(),
+        border_width: border_width_value.clone(),
+        border_color: box_css_p.border_color.clone(),
+        border_style: box_css_p.border_style.get_name(),
+        background_color: box_css_p.bg_color.clone(),
+        overflow: box_css_p.overflow.get_name(),
+        // 未设置时不输出 container-type container-name
+        container_type: if box_css_p.container_type == ContainerType::Normal {
+            String::default()
+        } else {
+            box_css_p.container_type.get_name()
+        },
+        container_name: box_css_p.container_name.clone(),
+        background_image: if box_css_p.bg_image == "0" {
+            "none".to_owned()
+        } else {
+            if box_css_p.bg_image.contains("linear-gradient") {
+                box_css_p.bg_image.clone()
+            } else {
+                "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
+            }
+        },
+        background_size: box_css_p.image_mode.get_name(),
+        backdrop_filter: box_css_p.backdrop.clone(),
+        cursor: box_css_p.cursor.get_name(),
+        white_space: box_css_p.white_space.get_name(),
+        min_width: if temp_min_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_min_width) {
+            temp_min_width_op
+        } else {
+            tmep_min_width_f
+        },
+        min_height: if temp_min_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_min_height) {
+            temp_min_height_op
+        } else {
+            temp_min_height_f
+        },
+        max_width: if temp_max_width == "auto" {
+            "none".to_owned()
+        } else if is_have_unit(temp_max_width) {
+            temp_max_width_op
+        } else {
+            tmep_max_width_f
+        },
+        max_height: if temp_max_height == "auto" {
+            "none".to_owned()
+        } else if is_have_unit(temp_max_height) {
+            temp_max_height_op
+        } else {
+            temp_max_height_f
+        },
+        box_shadow: box_css_p.shadow.clone(),
+        position: box_css_p.position.get_name(),
+        top: if box_css_p.top.contains("%") {
+            box_css_p.top.clone()
+        } else {
+            if box_css_p.top == "auto" || is_have_unit(&box_css_p.top) {
+                box_css_p.top.clone()
+            } else {
+                box_css_p.top.clone() + "px"
+            }
+        },
+        right: if box_css_p.right.contains("%") {
+            box_css_p.right.clone()
+        } else {
+            if box_css_p.right == "auto" || is_have_unit(&box_css_p.right) {
+                box_css_p.right.clone()
+            } else {
+                box_css_p.right.clone() + "px"
+            }
+        },
+        bottom: if box_css_p.bottom.contains("%") {
+            box_css_p.bottom.clone()
+        } else {
+            if box_css_p.bottom == "auto" || is_have_unit(&box_css_p.bottom) {
+                box_css_p.bottom.clone()
+            } else {
+                box_css_p.bottom.clone() + "px"
+            }
+        },
+        left: if box_css_p.left.contains("%") {
+            box_css_p.left.clone()
+        } else {
+            if box_css_p.left == "auto" || is_have_unit(&box_css_p.left) {
+                box_css_p.left.clone()
+            } else {
+                box_css_p.left.clone() + "px"
+            }
+        },
+        z_index: box_css_p.z_index.clone(),
+        opacity: box_css_p.opacity.clone(),
+
+        font_size: if is_have_unit(&box_css_p.font_size) {
+            box_css_p.font_size.to_owned()
+        } else {
+            box_css_p.font_size.clone() + "px"
+        },
+        color: box_css_p.color.clone(),
+        font_style: box_css_p.font_style.get_name(),
+        font_weight: box_css_p.font_weight.get_name(),
+        letter_spacing: if is_have_unit(&box_css_p.letter_spacing) {
+            box_css_p.letter_spacing.to_owned()
+        } else {
+            box_css_p.letter_spacing.clone() + "px"
+        },
+        line_height: if is_have_unit(&box_css_p.line_height) {
+            box_css_p.line_height.to_owned()
+        } else {
+            box_css_p.line_height.clone() + "px"
+        },
+        text_decoration: box_css_p.text_decoration.clone(),
+        text_align: box_css_p.text_align.get_name(),
+        word_break: box_css_p.word_break.get_name(),
+        flex_shrink: box_css_p.flex_shrink.clone(),
+
+        duration: box_css_p.duration.clone(),
+        timing_fn: box_css_p.timing_fn.get_name(),
+        hover_opacity: if box_css_p.h_opacity == String::default() {
+            box_css_p.opacity.clone()
+        } else {
+            box_css_p.h_opacity.clone()
+        },
+        hover_padding: if box_css_p.h_padding == String::default() {
+            padding_value
+        } else {
+            let temp = box_css_p.h_padding.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_margin: if box_css_p.h_margin == String::default() {
+            margin_value
+        } else {
+            let temp = box_css_p.h_margin.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_radius: if box_css_p.h_radius == String::default() {
+            radius_value
+        } else {
+            let temp = box_css_p.h_radius.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || x.contains("/") {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_border_width: if box_css_p.h_border_width == String::default() {
+            border_width_value
+        } else {
+            let temp = box_css_p.h_border_width.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_border_color: if box_css_p.h_border_color == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_border_color.clone()
+        },
+        hover_bg_color: if box_css_p.h_bg_color == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_bg_color.clone()
+        },
+        hover_color: if box_css_p.h_color == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_color.clone()
+        },
+        hover_shadow: if box_css_p.h_shadow == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_shadow.clone()
+        },
+        hover_width: if temp_h_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_width) {
+            temp_h_width_op
+        } else {
+            tmep_h_width_f
+        },
+        hover_height: if temp_h_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_height) {
+            temp_h_height_op
+        } else {
+            temp_h_height_f
+        },
+        dark_bg_color: if box_css_p.d_bg_color == String::default() {
+            box_css_p.bg_color.clone()
+        } else {
+            box_css_p.d_bg_color.clone()
+        },
+        dark_shadow: if box_css_p.d_shadow == String::default() {
+            box_css_p.shadow.clone()
+        } else {
+            box_css_p.d_shadow.clone()
+        },
+        dark_border_color: if box_css_p.d_border_color == String::default() {
+            box_css_p.border_color.clone()
+        } else {
+            box_css_p.d_border_color.clone()
+        },
+        dark_color: if box_css_p.d_color == String::default() {
+            box_css_p.color.clone()
+        } else {
+            box_css_p.d_color.clone()
+        },
+    }
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct FlexProps {
     pub flex: FlexWay,
-    #[prop_or(String::from("auto auto"))]
-    pub size: String,
-    #[prop_or(String::from("0"))]
-    pub padding: String,
-    #[prop_or(String::from("0"))]
-    pub margin: String,
-    #[prop_or(BoxSizing::BorderBox)]
-    pub box_sizing: BoxSizing,
-    #[prop_or(String::from("0"))]
-    pub radius: String,
-    #[prop_or(String::from("0"))]
-    pub border_width: String,
-    #[prop_or(String::from("transparent"))]
-    pub border_color: String,
-    #[prop_or(BorderStyle::No)]
-    pub border_style: BorderStyle,
-    #[prop_or(String::from("transparent"))]
-    pub bg_color: String,
-    #[prop_or(String::from("0"))]
-    pub bg_image: String,
-    #[prop_or(ImageMode::Auto)]
-    pub image_mode: ImageMode,
-    #[prop_or(Overflow::Visible)]
-    pub overflow: Overflow,
-    #[prop_or(String::from("none"))]
-    pub backdrop: String,
-    #[prop_or(Cursor::Unset)]
-    pub cursor: Cursor,
-    #[prop_or(WhiteSpace::Normal)]
-    pub white_space: WhiteSpace,
-    #[prop_or(String::from("auto auto"))]
-    pub min_size: String,
-    #[prop_or(String::from("auto auto"))]
-    pub max_size: String,
-
-    #[prop_or(String::from("none"))]
-    pub shadow: String,
-    #[prop_or(Position::Static)]
-    pub position: Position,
-    #[prop_or(String::from("auto"))]
-    pub top: String,
-    #[prop_or(String::from("auto"))]
-    pub right: String,
-    #[prop_or(String::from("auto"))]
-    pub bottom: String,
-    #[prop_or(String::from("auto"))]
-    pub left: String,
-    #[prop_or(String::from("auto"))]
-    pub z_index: String,
-    #[prop_or(String::from("inherit"))]
-    pub opacity: String,
-
-    #[prop_or(String::from("medium"))]
-    pub font_size: String,
-    #[prop_or(String::from("#181818"))]
-    pub color: String,
-    #[prop_or(FontStyle::Normal)]
-    pub font_style: FontStyle,
-    #[prop_or(FontWeight::Normal)]
-    pub font_weight: FontWeight,
-    #[prop_or(String::from("normal"))]
-    pub letter_spacing: String,
-    #[prop_or(String::from("normal"))]
-    pub line_height: String,
-    #[prop_or(String::from("none"))]
-    pub text_decoration: String,
-    #[prop_or(TextAlign::Left)]
-    pub text_align: TextAlign,
-    #[prop_or(WordBreak::Normal)]
-    pub word_break: WordBreak,
-    #[prop_or(String::from("1"))]
-    pub flex_shrink: String,
-
-    #[prop_or(String::from("0"))]
-    pub duration: String,
-    #[prop_or(TimingFn::Ease)]
-    pub timing_fn: TimingFn,
-    #[prop_or(String::from(""))]
-    pub h_opacity: String,
-    #[prop_or(String::from(""))]
-    pub h_padding: String,
-    #[prop_or(String::from(""))]
-    pub h_margin: String,
-    #[prop_or(String::from(""))]
-    pub h_radius: String,
-    #[prop_or(String::from(""))]
-    pub h_border_width: String,
-    #[prop_or(String::from(""))]
-    pub h_border_color: String,
     #[prop_or(String::from(""))]
-    pub h_bg_color: String,
+    pub preset: String,
     #[prop_or(String::from(""))]
-    pub h_color: String,
-    #[prop_or(String::from(""))]
-    pub h_shadow: String,
-    #[prop_or(String::from(""))]
-    pub h_size: String,
+    pub sx: String,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub padding: Spacing,
+    #[prop_or_default]
+    pub margin: Spacing,
+    #[prop_or_default]
+    pub safe: Option<SafeType>,
+    #[prop_or_default]
+    pub safe_mode: Option<SafeMode>,
+    #[prop_or_default]
+    pub box_sizing: Option<BoxSizing>,
+    #[prop_or_default]
+    pub radius: Radius,
+    #[prop_or_default]
+    pub border_width: Spacing,
+    #[prop_or_default]
+    pub border_color: Option<String>,
+    #[prop_or_default]
+    pub border_style: Option<BorderStyle>,
+    #[prop_or_default]
+    pub bg_color: Option<String>,
+    #[prop_or_default]
+    pub bg_image: Option<String>,
+    #[prop_or_default]
+    pub image_mode: Option<ImageMode>,
+    #[prop_or_default]
+    pub overflow: Option<Overflow>,
+    #[prop_or_default]
+    pub container_type: Option<ContainerType>,
+    #[prop_or_default]
+    pub container_name: Option<String>,
+    #[prop_or_default]
+    pub backdrop: Option<String>,
+    #[prop_or_default]
+    pub cursor: Option<Cursor>,
+    #[prop_or_default]
+    pub white_space: Option<WhiteSpace>,
+    #[prop_or_default]
+    pub min_size: Size,
+    #[prop_or_default]
+    pub max_size: Size,
+
+    #[prop_or_default]
+    pub shadow: Option<String>,
+    #[prop_or_default]
+    pub position: Option<Position>,
+    #[prop_or_default]
+    pub top: Option<String>,
+    #[prop_or_default]
+    pub right: Option<String>,
+    #[prop_or_default]
+    pub bottom: Option<String>,
+    #[prop_or_default]
+    pub left: Option<String>,
+    #[prop_or_default]
+    pub z_index: ZIndex,
+    #[prop_or_default]
+    pub opacity: Opacity,
+
+    #[prop_or_default]
+    pub font_size: Option<String>,
+    #[prop_or_default]
+    pub color: Option<String>,
+    #[prop_or_default]
+    pub font_style: Option<FontStyle>,
+    #[prop_or_default]
+    pub font_weight: Option<FontWeight>,
+    #[prop_or_default]
+    pub letter_spacing: Option<String>,
+    #[prop_or_default]
+    pub line_height: Option<String>,
+    #[prop_or_default]
+    pub text_decoration: Option<String>,
+    #[prop_or_default]
+    pub text_align: Option<TextAlign>,
+    #[prop_or_default]
+    pub word_break: Option<WordBreak>,
+    #[prop_or_default]
+    pub flex_shrink: Option<String>,
+
+    #[prop_or_default]
+    pub duration: Duration,
+    #[prop_or_default]
+    pub timing_fn: Option<TimingFn>,
+    #[prop_or_default]
+    pub h_opacity: Opacity,
+    #[prop_or_default]
+    pub h_padding: Spacing,
+    #[prop_or_default]
+    pub h_margin: Spacing,
+    #[prop_or_default]
+    pub h_radius: Radius,
+    #[prop_or_default]
+    pub h_border_width: Spacing,
+    #[prop_or_default]
+    pub h_border_color: Option<String>,
+    #[prop_or_default]
+    pub h_bg_color: Option<String>,
+    #[prop_or_default]
+    pub h_color: Option<String>,
+    #[prop_or_default]
+    pub h_shadow: Option<String>,
+    #[prop_or_default]
+    pub h_size: Size,
+
+    #[prop_or_default]
+    pub d_bg_color: Option<String>,
+    #[prop_or_default]
+    pub d_shadow: Option<String>,
+    #[prop_or_default]
+    pub d_border_color: Option<String>,
+    #[prop_or_default]
+    pub d_color: Option<String>,
 
     #[prop_or(String::from(""))]
-    pub d_bg_color: String,
-    #[prop_or(String::from(""))]
-    pub d_shadow: String,
-    #[prop_or(String::from(""))]
-    pub d_border_color: String,
-    #[prop_or(String::from(""))]
-    pub d_color: String,
+    pub testid: String,
 
     #[prop_or_default]
     pub children: Children,
@@ -255,90 +828,118 @@ pub struct FlexProps {
     pub node: NodeRef,
 }
 
-/// ### 使用示例
-/// ```
-/// flex: FlexWay,
-/// size: String,  // "22" "10 20" "auto 100%" "50% calc(50vh-100px)"
-/// padding: String, // "1 2 2 1"
-/// margin: String, // "1 2 2 1"
-/// box_sizing: BoxSizing,
-/// radius: String,   // "10" "10% 30% 50% 70%"
-/// border_width: String, // "2" "10 2 6 0"
-/// border_color: String, // "red" "#f22 #cca #663 #0aa"
-/// border_style: BorderStyle,
-/// bg_color: String,  // "#f22"
-/// bg_image: String,  // "https://xxx.png" "linear-gradient(#e66465, #9198e5)"
-/// image_mode: ImageMode,
-/// overflow: Overflow,
-/// backdrop: String,  // "blur(10px)"
-/// cursor: Cursor,
-/// white_space: WhiteSpace,
-/// min_size: String,  // "100 auto"
-/// max_size: String,  // "200 auto"
-/// shadow: String, // "10px 5px 5px red"
-/// position: Position,
-/// top: String,
-/// right: String,
-/// bottom: String,
-/// left: String,
-/// z_index: String,
-/// opacity: String,
-/// font_size: String,
-/// color: String,
-/// font_style: FontStyle,
-/// font_weight: FontWeight,
-/// letter_spacing: String,
-/// line_height: String,
-/// text_decoration: String,
-/// text_align: TextAlign,
-/// word_break: WordBreak,
-/// flex_shrink: String,
-/// duration: String, // transition 时间 s
-/// timing_fn: TimingFn, // transition 的动画方式
-/// h_opacity: String,  //hover 样式 "0.7"
-/// h_padding: String,  //hover 样式 "0 0 12 12"
-/// h_margin: String,  //hover 样式 "0 0 12 12"
-/// h_radius: String,    //hover 样式 "12"
-/// h_border_width: String, //hover 样式 "12"
-/// h_border_color: String, //hover 样式 "#ffa"
-/// h_bg_color: String,  // hover 样式
-/// h_color: String,  // hover 样式
-/// h_shadow: String,  // hover 样式
-/// h_size: String,  // hover 样式
-/// d_bg_color: String,  // dark 模式
-/// d_shadow: String, // dark 模式
-/// d_border_color: String, // dark 模式
-/// d_color: String, // dark 模式
-/// ```
-#[function_component]
-pub fn Flex(props: &FlexProps) -> Html {
-    let box_css_p: FlexCssProps = FlexCssProps {
+resolved_props!(
+    ResolvedFlexProps for FlexProps {
+        size: Size = Size::from("auto auto"),
+        padding: Spacing = Spacing::from("0"),
+        margin: Spacing = Spacing::from("0"),
+        safe: SafeType = SafeType::None,
+        safe_mode: SafeMode = SafeMode::Padding,
+        box_sizing: BoxSizing = BoxSizing::BorderBox,
+        radius: Radius = Radius::from("0"),
+        border_width: Spacing = Spacing::from("0"),
+        border_color: String = String::from("transparent"),
+        border_style: BorderStyle = BorderStyle::No,
+        bg_color: String = String::from("transparent"),
+        bg_image: String = String::from("0"),
+        image_mode: ImageMode = ImageMode::Auto,
+        overflow: Overflow = Overflow::Visible,
+        container_type: ContainerType = ContainerType::Normal,
+        container_name: String = String::from(""),
+        backdrop: String = String::from("none"),
+        cursor: Cursor = Cursor::Unset,
+        white_space: WhiteSpace = WhiteSpace::Normal,
+        min_size: Size = Size::from("auto auto"),
+        max_size: Size = Size::from("auto auto"),
+        shadow: String = String::from("none"),
+        position: Position = Position::Static,
+        top: String = String::from("auto"),
+        right: String = String::from("auto"),
+        bottom: String = String::from("auto"),
+        left: String = String::from("auto"),
+        z_index: ZIndex = ZIndex::from("auto"),
+        opacity: Opacity = Opacity::from("inherit"),
+        font_size: String = String::from("medium"),
+        color: String = String::from("#181818"),
+        font_style: FontStyle = FontStyle::Normal,
+        font_weight: FontWeight = FontWeight::Normal,
+        letter_spacing: String = String::from("normal"),
+        line_height: String = String::from("normal"),
+        text_decoration: String = String::from("none"),
+        text_align: TextAlign = TextAlign::Left,
+        word_break: WordBreak = WordBreak::Normal,
+        flex_shrink: String = String::from("1"),
+        duration: Duration = Duration::from("0"),
+        timing_fn: TimingFn = TimingFn::Ease,
+        h_opacity: Opacity = Opacity::from(""),
+        h_padding: Spacing = Spacing::from(""),
+        h_margin: Spacing = Spacing::from(""),
+        h_radius: Radius = Radius::from(""),
+        h_border_width: Spacing = Spacing::from(""),
+        h_border_color: String = String::from(""),
+        h_bg_color: String = String::from(""),
+        h_color: String = String::from(""),
+        h_shadow: String = String::from(""),
+        h_size: Size = Size::from(""),
+        d_bg_color: String = String::from(""),
+        d_shadow: String = String::from(""),
+        d_border_color: String = String::from(""),
+        d_color: String = String::from(""),
+    }
+    flex: FlexWay,
+    preset: String,
+    sx: String,
+    testid: String,
+    children: Children,
+    onclick: Callback<MouseEvent>,
+    node: NodeRef,
+);
+
+/// 组件生成的 css，可在原生环境中调用（如测试）
+/// 断点、设计稿换算、sx 的主题使用默认值，不含 ConfigProvider 和 preset
+pub fn flex_css(props: &FlexProps) -> String {
+    let breakpoints = Breakpoints::default();
+    let sx = SxStyle::parse(&props.sx, &Theme::default(), &breakpoints);
+    let props = resolve_flex_props(props, &sx, None);
+    build_flex_css(
+        &get_flex_css_props(&props),
+        &breakpoints,
+        &Design::default(),
+    )
+}
+
+fn get_flex_css_props(props: &ResolvedFlexProps) -> FlexCssProps {
+    FlexCssProps {
         flex: props.flex.clone(),
-        size: props.size.clone(),
-        padding: props.padding.clone(),
-        margin: props.margin.clone(),
+        size: props.size.to_string(),
+        padding: props.padding.to_string(),
+        margin: props.margin.to_string(),
+        safe: props.safe.clone(),
+        safe_mode: props.safe_mode.clone(),
         box_sizing: props.box_sizing.clone(),
-        radius: props.radius.clone(),
-        border_width: props.border_width.clone(),
+        radius: props.radius.to_string(),
+        border_width: props.border_width.to_string(),
         border_color: props.border_color.clone(),
         border_style: props.border_style.clone(),
         bg_color: props.bg_color.clone(),
         bg_image: props.bg_image.clone(),
         image_mode: props.image_mode.clone(),
         overflow: props.overflow.clone(),
+        container_type: props.container_type.clone(),
+        container_name: props.container_name.clone(),
         backdrop: props.backdrop.clone(),
         cursor: props.cursor.clone(),
         white_space: props.white_space.clone(),
-        min_size: props.min_size.clone(),
-        max_size: props.max_size.clone(),
+        min_size: props.min_size.to_string(),
+        max_size: props.max_size.to_string(),
         shadow: props.shadow.clone(),
         position: props.position.clone(),
         top: props.top.clone(),
         right: props.right.clone(),
         bottom: props.bottom.clone(),
         left: props.left.clone(),
-        z_index: props.z_index.clone(),
-        opacity: props.opacity.clone(),
+        z_index: props.z_index.to_string(),
+        opacity: props.opacity.to_string(),
 
         font_size: props.font_size.clone(),
         color: props.color.clone(),
@@ -351,613 +952,256 @@ pub fn Flex(props: &FlexProps) -> Html {
         word_break: props.word_break.clone(),
         flex_shrink: props.flex_shrink.clone(),
 
-        duration: props.duration.clone(),
+        duration: props.duration.to_string(),
         timing_fn: props.timing_fn.clone(),
-        h_opacity: props.h_opacity.clone(),
-        h_padding: props.h_padding.clone(),
-        h_margin: props.h_margin.clone(),
-        h_radius: props.h_radius.clone(),
-        h_border_width: props.h_border_width.clone(),
+        h_opacity: props.h_opacity.to_string(),
+        h_padding: props.h_padding.to_string(),
+        h_margin: props.h_margin.to_string(),
+        h_radius: props.h_radius.to_string(),
+        h_border_width: props.h_border_width.to_string(),
         h_border_color: props.h_border_color.clone(),
         h_bg_color: props.h_bg_color.clone(),
         h_color: props.h_color.clone(),
         h_shadow: props.h_shadow.clone(),
-        h_size: props.h_size.clone(),
+        h_size: props.h_size.to_string(),
 
         d_bg_color: props.d_bg_color.clone(),
         d_shadow: props.d_shadow.clone(),
         d_border_color: props.d_border_color.clone(),
         d_color: props.d_color.clone(),
-    };
-
-    let box_css = use_memo(
-        |box_css_p| {
-            let f_vec: Vec<String> = box_css_p
-                .flex
-                .get_name()
-                .split("")
-                .filter(|x| x != &String::default() && x != &"f")
-                .map(|x| match x {
-                    "r" => "row".to_string(),
-                    "c" => "center".to_string(),
-                    "b" => "space-between".to_string(),
-                    "a" => "space-around".to_string(),
-                    "e" => "flex-end".to_string(),
-                    "s" => "flex-start".to_string(),
-                    _ => "center".to_string(),
-                })
-                .collect();
-            let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
-            let temp_h_size = if box_css_p.h_size == String::default() {
-                temp_size.clone()
-            } else {
-                box_css_p.h_size.split(" ").collect::<Vec<&str>>()
-            };
-            let temp_min_size = box_css_p.min_size.split(" ").collect::<Vec<&str>>();
-            let temp_max_size = box_css_p.max_size.split(" ").collect::<Vec<&str>>();
-
-            let temp_width = temp_size[0];
-            let temp_height = if temp_size.len() == 1 {
-                temp_size[0]
-            } else {
-                temp_size[1]
-            };
-
-            let temp_h_width = temp_h_size[0];
-            let temp_h_height = if temp_h_size.len() == 1 {
-                temp_h_size[0]
-            } else {
-                temp_h_size[1]
-            };
-
-            let temp_min_width = temp_min_size[0];
-            let temp_min_height = if temp_min_size.len() == 1 {
-                temp_min_size[0]
-            } else {
-                temp_min_size[1]
-            };
-            let temp_max_width = temp_max_size[0];
-            let temp_max_height = if temp_max_size.len() == 1 {
-                temp_max_size[0]
-            } else {
-                temp_max_size[1]
-            };
+    }
+}
 
-            let temp_width_op = add_op_space(temp_width);
-            let tmep_width_f = if temp_width.contains("%") {
-                format!("{}", temp_width)
-            } else {
-                format!("{}{}", temp_width, "px")
-            };
-            let temp_height_op = add_op_space(temp_height);
-            let temp_height_f = if temp_height.contains("%") {
-                format!("{}", temp_height)
-            } else {
-                format!("{}{}", temp_height, "px")
-            };
+/// 依次使用内置默认值、ConfigProvider、preset 和 sx、组件中设置的属性
+fn resolve_flex_props(
+    props: &FlexProps,
+    sx: &SxStyle,
+    config: Option<&FlexProps>,
+) -> ResolvedFlexProps {
+    let mut p = ResolvedFlexProps::new(props, config);
+    apply_flex_sx(&mut p, props, sx);
+    p.apply(props);
+    p
+}
 
-            let temp_h_width_op = add_op_space(temp_h_width);
-            let tmep_h_width_f = if temp_h_width.contains("%") {
-                format!("{}", temp_h_width)
-            } else {
-                format!("{}{}", temp_h_width, "px")
-            };
-            let temp_h_height_op = add_op_space(temp_h_height);
-            let temp_h_height_f = if temp_h_height.contains("%") {
-                format!("{}", temp_h_height)
-            } else {
-                format!("{}{}", temp_h_height, "px")
-            };
+fn apply_flex_sx(p: &mut ResolvedFlexProps, props: &FlexProps, sx: &SxStyle) {
+    sx_props!(
+        p,
+        props,
+        sx,
+        size,
+        padding,
+        margin,
+        radius,
+        border_width,
+        border_color,
+        border_style,
+        bg_color,
+        bg_image,
+        container_name,
+        backdrop,
+        min_size,
+        max_size,
+        shadow,
+        position,
+        top,
+        right,
+        bottom,
+        left,
+        z_index,
+        opacity,
+        font_size,
+        color,
+        letter_spacing,
+        line_height,
+        text_decoration,
+        flex_shrink,
+        duration,
+        h_opacity,
+        h_padding,
+        h_margin,
+        h_radius,
+        h_border_width,
+        h_border_color,
+        h_bg_color,
+        h_color,
+        h_shadow,
+        h_size,
+        d_bg_color,
+        d_shadow,
+        d_border_color,
+        d_color
+    );
+}
 
-            let temp_min_width_op = add_op_space(temp_min_width);
-            let tmep_min_width_f = if temp_min_width.contains("%") {
-                format!("{}", temp_min_width)
-            } else {
-                format!("{}{}", temp_min_width, "px")
-            };
-            let temp_min_height_op = add_op_space(temp_min_height);
-            let temp_min_height_f = if temp_min_height.contains("%") {
-                format!("{}", temp_min_height)
-            } else {
-                format!("{}{}", temp_min_height, "px")
-            };
-            let temp_max_width_op = add_op_space(temp_max_width);
-            let tmep_max_width_f = if temp_max_width.contains("%") {
-                format!("{}", temp_max_width)
-            } else {
-                format!("{}{}", temp_max_width, "px")
-            };
-            let temp_max_height_op = add_op_space(temp_max_height);
-            let temp_max_height_f = if temp_max_height.contains("%") {
-                format!("{}", temp_max_height)
-            } else {
-                format!("{}{}", temp_max_height, "px")
-            };
-
-            let temp_padding = box_css_p.padding.as_str();
-            let temp_margin = box_css_p.margin.as_str();
-            let temp_radius = box_css_p.radius.as_str();
-            let temp_border_width = box_css_p.border_width.as_str();
-
-            let temp_padding_c = temp_padding
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let padding_value = if is_have_unit(temp_padding) {
-                temp_padding.to_owned()
-            } else {
-                temp_padding_c
-            };
-            let temp_margin_c = temp_margin
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let margin_value = if is_have_unit(temp_margin) {
-                temp_margin.to_owned()
-            } else {
-                temp_margin_c
-            };
-            let temp_radius_c = temp_radius
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") || x.contains("/") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let radius_value = if is_have_unit(temp_radius) {
-                temp_radius.to_owned()
-            } else {
-                temp_radius_c
-            };
-            let temp_border_width_c = temp_border_width
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let border_width_value = if is_have_unit(temp_border_width) {
-                temp_border_width.to_owned()
-            } else {
-                temp_border_width_c
-            };
-            FlexCss {
-                flex_direction: if f_vec[0] == "center".to_owned() {
-                    "column".to_string()
-                } else {
-                    f_vec[0].clone()
-                },
-                justify_content: f_vec[1].clone(),
-                align_items: f_vec[2].clone(),
-                width: if temp_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_width) {
-                    temp_width_op
-                } else {
-                    tmep_width_f
-                },
-                height: if temp_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_height) {
-                    temp_height_op
-                } else {
-                    temp_height_f
-                },
-                padding: padding_value.clone(),
-                margin: margin_value.clone(),
-                box_sizing: box_css_p.box_sizing.get_name(),
-                border_radius: radius_value.clone(),
-                border_width: border_width_value.clone(),
-                border_color: box_css_p.border_color.clone(),
-                border_style: box_css_p.border_style.get_name(),
-                background_color: box_css_p.bg_color.clone(),
-                overflow: box_css_p.overflow.get_name(),
-                background_image: if box_css_p.bg_image == "0" {
-                    "none".to_owned()
-                } else {
-                    if box_css_p.bg_image.contains("linear-gradient") {
-                        box_css_p.bg_image.clone()
-                    } else {
-                        "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
-                    }
-                },
-                background_size: box_css_p.image_mode.get_name(),
-                backdrop_filter: box_css_p.backdrop.clone(),
-                cursor: box_css_p.cursor.get_name(),
-                white_space: box_css_p.white_space.get_name(),
-                min_width: if temp_min_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_min_width) {
-                    temp_min_width_op
-                } else {
-                    tmep_min_width_f
-                },
-                min_height: if temp_min_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_min_height) {
-                    temp_min_height_op
-                } else {
-                    temp_min_height_f
-                },
-                max_width: if temp_max_width == "auto" {
-                    "none".to_owned()
-                } else if is_have_unit(temp_max_width) {
-                    temp_max_width_op
-                } else {
-                    tmep_max_width_f
-                },
-                max_height: if temp_max_height == "auto" {
-                    "none".to_owned()
-                } else if is_have_unit(temp_max_height) {
-                    temp_max_height_op
-                } else {
-                    temp_max_height_f
-                },
-                box_shadow: box_css_p.shadow.clone(),
-                position: box_css_p.position.get_name(),
-                top: if box_css_p.top.contains("%") {
-                    box_css_p.top.clone()
-                } else {
-                    if box_css_p.top == String::from("auto") {
-                        box_css_p.top.clone()
-                    } else {
-                        box_css_p.top.clone() + "px"
-                    }
-                },
-                right: if box_css_p.right.contains("%") {
-                    box_css_p.right.clone()
-                } else {
-                    if box_css_p.right == String::from("auto") {
-                        box_css_p.right.clone()
-                    } else {
-                        box_css_p.right.clone() + "px"
-                    }
-                },
-                bottom: if box_css_p.bottom.contains("%") {
-                    box_css_p.bottom.clone()
-                } else {
-                    if box_css_p.bottom == String::from("auto") {
-                        box_css_p.bottom.clone()
-                    } else {
-                        box_css_p.bottom.clone() + "px"
-                    }
-                },
-                left: if box_css_p.left.contains("%") {
-                    box_css_p.left.clone()
-                } else {
-                    if box_css_p.left == String::from("auto") {
-                        box_css_p.left.clone()
-                    } else {
-                        box_css_p.left.clone() + "px"
-                    }
-                },
-                z_index: box_css_p.z_index.clone(),
-                opacity: box_css_p.opacity.clone(),
-
-                font_size: if is_have_unit(&box_css_p.font_size) {
-                    box_css_p.font_size.to_owned()
-                } else {
-                    box_css_p.font_size.clone() + "px"
-                },
-                color: box_css_p.color.clone(),
-                font_style: box_css_p.font_style.get_name(),
-                font_weight: box_css_p.font_weight.get_name(),
-                letter_spacing: if is_have_unit(&box_css_p.letter_spacing) {
-                    box_css_p.letter_spacing.to_owned()
-                } else {
-                    box_css_p.letter_spacing.clone() + "px"
-                },
-                line_height: if is_have_unit(&box_css_p.line_height) {
-                    box_css_p.line_height.to_owned()
-                } else {
-                    box_css_p.line_height.clone() + "px"
-                },
-                text_decoration: box_css_p.text_decoration.clone(),
-                text_align: box_css_p.text_align.get_name(),
-                word_break: box_css_p.word_break.get_name(),
-                flex_shrink: box_css_p.flex_shrink.clone(),
-
-                duration: box_css_p.duration.clone(),
-                timing_fn: box_css_p.timing_fn.get_name(),
-                hover_opacity: if box_css_p.h_opacity == String::default() {
-                    box_css_p.opacity.clone()
-                } else {
-                    box_css_p.h_opacity.clone()
-                },
-                hover_padding: if box_css_p.h_padding == String::default() {
-                    padding_value
-                } else {
-                    let temp = box_css_p.h_padding.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_margin: if box_css_p.h_margin == String::default() {
-                    margin_value
-                } else {
-                    let temp = box_css_p.h_margin.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_radius: if box_css_p.h_radius == String::default() {
-                    radius_value
-                } else {
-                    let temp = box_css_p.h_radius.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") || x.contains("/") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_border_width: if box_css_p.h_border_width == String::default() {
-                    border_width_value
-                } else {
-                    let temp = box_css_p.h_border_width.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_border_color: if box_css_p.h_border_color == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_border_color.clone()
-                },
-                hover_bg_color: if box_css_p.h_bg_color == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_bg_color.clone()
-                },
-                hover_color: if box_css_p.h_color == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_color.clone()
-                },
-                hover_shadow: if box_css_p.h_shadow == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_shadow.clone()
-                },
-                hover_width: if temp_h_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_width) {
-                    temp_h_width_op
-                } else {
-                    tmep_h_width_f
-                },
-                hover_height: if temp_h_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_height) {
-                    temp_h_height_op
-                } else {
-                    temp_h_height_f
-                },
-                dark_bg_color: if box_css_p.d_bg_color == String::default() {
-                    box_css_p.bg_color.clone()
-                } else {
-                    box_css_p.d_bg_color.clone()
-                },
-                dark_shadow: if box_css_p.d_shadow == String::default() {
-                    box_css_p.shadow.clone()
-                } else {
-                    box_css_p.d_shadow.clone()
-                },
-                dark_border_color: if box_css_p.d_border_color == String::default() {
-                    box_css_p.border_color.clone()
-                } else {
-                    box_css_p.d_border_color.clone()
-                },
-                dark_color: if box_css_p.d_color == String::default() {
-                    box_css_p.color.clone()
-                } else {
-                    box_css_p.d_color.clone()
-                },
-            }
-        },
+fn build_flex_css(box_css_p: &FlexCssProps, breakpoints: &Breakpoints, design: &Design) -> String {
+    let box_css_p = &design_props!(
         box_css_p,
+        design,
+        size,
+        padding,
+        margin,
+        radius,
+        border_width,
+        min_size,
+        max_size,
+        top,
+        right,
+        bottom,
+        left,
+        font_size,
+        letter_spacing,
+        line_height,
+        h_padding,
+        h_margin,
+        h_radius,
+        h_border_width,
+        h_size
     );
+    let (base, points) = responsive_props!(
+        box_css_p,
+        breakpoints,
+        size,
+        padding,
+        margin,
+        radius,
+        border_width,
+        border_color,
+        bg_color,
+        bg_image,
+        container_name,
+        backdrop,
+        min_size,
+        max_size,
+        shadow,
+        top,
+        right,
+        bottom,
+        left,
+        z_index,
+        opacity,
+        font_size,
+        color,
+        letter_spacing,
+        line_height,
+        text_decoration,
+        flex_shrink,
+        duration,
+        h_opacity,
+        h_padding,
+        h_margin,
+        h_radius,
+        h_border_width,
+        h_border_color,
+        h_bg_color,
+        h_color,
+        h_shadow,
+        h_size,
+        d_bg_color,
+        d_shadow,
+        d_border_color,
+        d_color,
+    );
+    let points = points
+        .iter()
+        .map(|(name, p)| (name.clone(), get_flex_css(p).to_blocks()))
+        .collect();
+    responsive_css(&get_flex_css(&base).to_blocks(), points, breakpoints)
+}
 
-    let class = css!(
-        r#"
-            display: flex;
-            flex-direction: ${flex_direction};
-            justify-content: ${justify_content};
-            align-items: ${align_items};
-
-            width: ${width};
-            height: ${height};
-            padding: ${padding};
-            margin: ${margin};
-            box-sizing: ${box_sizing};
-            border-radius: ${radius};
-            border-width: ${border_width};
-            border-color: ${border_color};
-            border-style: ${border_style};
-            background-color: ${bg_color};
-            overflow: ${overflow};
-            background-image: ${bg_img};
-            background-repeat: no-repeat;
-            background-position: center;
-            background-size: ${bg_size};
-            backdrop-filter: ${backdrop};
-            cursor: ${cursor};
-            white-space: ${white_space};
-            min-width: ${min_width};
-            min-height: ${min_height};
-            max-width: ${max_width};
-            max-height: ${max_height};
-            box-shadow: ${box_shadow};
-            position: ${position};
-            left: ${left};
-            top: ${top};
-            right: ${right};
-            bottom: ${bottom};
-            z-index: ${z_index};
-            opacity: ${opacity};
-
-            font-size: ${font_size};
-            color: ${color};
-            font-style: ${font_style};
-            font-weight: ${font_weight};
-            letter-spacing: ${letter_spacing};
-            line-height: ${line_height};
-            text-decoration: ${text_decoration};
-            text-align: ${text_align};
-            word-break: ${word_break};
-            flex-shrink: ${flex_shrink};
-
-
-            transition: all ${duration}s ${timing_fn};
-
-            &:hover {
-                background-color: ${hover_bg_color};
-                color: ${hover_color};
-                box-shadow: ${hover_shadow};
-                width: ${hover_width};
-                height: ${hover_height};
-                padding: ${hover_padding};
-                margin: ${hover_margin};
-                border-radius: ${hover_radius};
-                border-width: ${hover_border_width};
-                border-color: ${hover_border_color};
-                opacity: ${hover_opacity};
-            }
+/// ### 使用示例
+/// ```text
+/// flex: FlexWay,
+/// preset: String, // 样式预设名称，多个用空格分隔 "card elevated"，在 ConfigProvider 中注册
+/// sx: String, // 工具类 "p-4 mx-2 bg-primary rounded-lg shadow-md hover:bg-primary-600 dark:text-white"
+/// size: Size,  // "22" "10 20" "auto 100%" "50% calc(50vh-100px)"
+/// padding: Spacing, // "1 2 2 1"
+/// margin: Spacing, // "1 2 2 1"
+/// safe: SafeType, // 安全区域，加在原有的 padding 或 margin 上
+/// safe_mode: SafeMode, // 安全区域作为 padding 或 margin
+/// box_sizing: BoxSizing,
+/// radius: Radius,   // "10" "10% 30% 50% 70%"
+/// border_width: Spacing, // "2" "10 2 6 0"
+/// border_color: String, // "red" "#f22 #cca #663 #0aa"
+/// border_style: BorderStyle,
+/// bg_color: String,  // "#f22"
+/// bg_image: String,  // "https://xxx.png" "linear-gradient(#e66465, #9198e5)"
+/// image_mode: ImageMode,
+/// overflow: Overflow,
+/// container_type: ContainerType, // 作为容器查询的容器，子组件可使用 "@md:50%"
+/// container_name: String, // 容器名称，子组件可使用 "@md/card:50%"
+/// backdrop: String,  // "blur(10px)"
+/// cursor: Cursor,
+/// white_space: WhiteSpace,
+/// min_size: Size,  // "100 auto"
+/// max_size: Size,  // "200 auto"
+/// shadow: String, // "10px 5px 5px red"
+/// position: Position,
+/// top: String,
+/// right: String,
+/// bottom: String,
+/// left: String,
+/// z_index: ZIndex,
+/// opacity: Opacity,
+/// font_size: String,
+/// color: String,
+/// font_style: FontStyle,
+/// font_weight: FontWeight,
+/// letter_spacing: String,
+/// line_height: String,
+/// text_decoration: String,
+/// text_align: TextAlign,
+/// word_break: WordBreak,
+/// flex_shrink: String,
+/// duration: Duration, // transition 时间 s
+/// timing_fn: TimingFn, // transition 的动画方式
+/// h_opacity: Opacity,  //hover 样式 "0.7"
+/// h_padding: Spacing,  //hover 样式 "0 0 12 12"
+/// h_margin: Spacing,  //hover 样式 "0 0 12 12"
+/// h_radius: Radius,    //hover 样式 "12"
+/// h_border_width: Spacing, //hover 样式 "12"
+/// h_border_color: String, //hover 样式 "#ffa"
+/// h_bg_color: String,  // hover 样式
+/// h_color: String,  // hover 样式
+/// h_shadow: String,  // hover 样式
+/// h_size: Size,  // hover 样式
+/// d_bg_color: String,  // dark 模式
+/// d_shadow: String, // dark 模式
+/// d_border_color: String, // dark 模式
+/// d_color: String, // dark 模式
+/// testid: String, // 渲染为 data-testid
+/// ```
+#[function_component]
+pub fn Flex(props: &FlexProps) -> Html {
+    let config = use_config();
+    let breakpoints = use_breakpoints();
+    let sx = use_memo(
+        |(preset, sx, config, breakpoints)| {
+            let sx = format!("{} {}", config.expand_presets(preset), sx);
+            SxStyle::parse(&sx, &config.get_theme(), breakpoints)
+        },
+        (
+            props.preset.clone(),
+            props.sx.clone(),
+            config.clone(),
+            breakpoints.clone(),
+        ),
+    );
+    let props = &resolve_flex_props(props, &sx, config.flex_props.as_deref());
+    let box_css_p = get_flex_css_props(props);
 
-            @media (prefers-color-scheme: dark) {
-              & {
-                background-color: ${dark_bg_color};
-                color: ${dark_color};
-                box-shadow: ${dark_shadow};
-                border-color: ${dark_border_color};
-              }
-            }
-        "#,
-        flex_direction = box_css.flex_direction,
-        justify_content = box_css.justify_content,
-        align_items = box_css.align_items,
-        width = box_css.width,
-        height = box_css.height,
-        padding = box_css.padding,
-        margin = box_css.margin,
-        box_sizing = box_css.box_sizing,
-        radius = box_css.border_radius,
-        border_width = box_css.border_width,
-        border_color = box_css.border_color,
-        border_style = box_css.border_style,
-        bg_color = box_css.background_color,
-        bg_img = box_css.background_image,
-        bg_size = box_css.background_size,
-        overflow = box_css.overflow,
-        backdrop = box_css.backdrop_filter,
-        cursor = box_css.cursor,
-        white_space = box_css.white_space,
-        min_width = box_css.min_width,
-        min_height = box_css.min_height,
-        max_width = box_css.max_width,
-        max_height = box_css.max_height,
-        box_shadow = box_css.box_shadow,
-        position = box_css.position,
-        top = box_css.top,
-        right = box_css.right,
-        bottom = box_css.bottom,
-        left = box_css.left,
-        z_index = box_css.z_index,
-        opacity = box_css.opacity,
-        font_size = box_css.font_size,
-        color = box_css.color,
-        font_style = box_css.font_style,
-        font_weight = box_css.font_weight,
-        letter_spacing = box_css.letter_spacing,
-        line_height = box_css.line_height,
-        text_decoration = box_css.text_decoration,
-        text_align = box_css.text_align,
-        word_break = box_css.word_break,
-        flex_shrink = box_css.flex_shrink,
-        duration = box_css.duration,
-        timing_fn = box_css.timing_fn,
-        hover_opacity = box_css.hover_opacity,
-        hover_padding = box_css.hover_padding,
-        hover_margin = box_css.hover_margin,
-        hover_radius = box_css.hover_radius,
-        hover_border_width = box_css.hover_border_width,
-        hover_border_color = box_css.hover_border_color,
-        hover_bg_color = box_css.hover_bg_color,
-        hover_color = box_css.hover_color,
-        hover_shadow = box_css.hover_shadow,
-        hover_width = box_css.hover_width,
-        hover_height = box_css.hover_height,
-        dark_bg_color = box_css.dark_bg_color,
-        dark_shadow = box_css.dark_shadow,
-        dark_border_color = box_css.dark_border_color,
-        dark_color = box_css.dark_color,
+    let design = use_design();
+    let style = use_memo(
+        |(box_css_p, breakpoints, design)| {
+            style_source(build_flex_css(box_css_p, breakpoints, design))
+        },
+        (box_css_p, breakpoints, design),
     );
+    let class = (*style).clone();
 
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
     html! {
-        <div {class} onclick={props.onclick.clone()} ref={props.node.clone()}>
+        <div {class} data-testid={testid} onclick={props.onclick.clone()} ref={props.node.clone()}>
         { for props.children.iter() }
         </div>
     }
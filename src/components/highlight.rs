@@ -0,0 +1,307 @@
+use wasm_bindgen::{JsCast, JsValue};
+use yew::{
+    function_component, html, use_effect_with_deps, use_memo, use_state_eq, Html, Properties,
+};
+
+use crate::prelude::FontWeight;
+use crate::utils::{style_source, CssBlocks};
+
+/// 去掉常见拉丁字母的变音符号，"é" => "e"，其余字符不变
+fn strip_diacritic(c: char) -> char {
+    const FROM: &str = "ÀÁÂÃÄÅàáâãäåĀāĂăĄąÇçĆćĈĉĊċČčĎďĐđÈÉÊËèéêëĒēĔĕĖėĘęĚěĜĝĞğĠġĢģĤĥĦħÌÍÎÏìíîïĨĩĪīĬĭĮįİıĴĵĶķĹĺĻļĽľĿŀŁłÑñŃńŅņŇňÒÓÔÕÖØòóôõöøŌōŎŏŐőŔŕŖŗŘřŚśŜŝŞşŠšŢţŤťŦŧÙÚÛÜùúûüŨũŪūŬŭŮůŰűŲųŴŵÝýÿŶŷŸŹźŻżŽžǍǎǏǐǑǒǓǔǕǖǗǘǙǚǛǜ";
+    const TO: &str = "AAAAAAaaaaaaAaAaAaCcCcCcCcCcDdDdEEEEeeeeEeEeEeEeEeGgGgGgGgHhHhIIIIiiiiIiIiIiIiIiJjKkLlLlLlLlLlNnNnNnNnOOOOOOooooooOoOoOoRrRrRrSsSsSsSsTtTtTtUUUUuuuuUuUuUuUuUuUuWwYyyYyYZzZzZzAaIiOoUuUuUuUuUu";
+    FROM.chars()
+        .position(|x| x == c)
+        .and_then(|i| TO.chars().nth(i))
+        .unwrap_or(c)
+}
+
+/// 组合用变音符号 U+0300 ~ U+036F，如分解形式 "e\u{301}" 中的 "\u{301}"
+fn is_combining(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// 按选项转换后的文字，以及转换后每个字符对应原文中的下标
+fn fold_text(text: &[char], ignore_case: bool, ignore_diacritics: bool) -> (Vec<char>, Vec<usize>) {
+    let mut folded = vec![];
+    let mut index = vec![];
+    for (i, c) in text.iter().enumerate() {
+        if ignore_diacritics && is_combining(*c) {
+            continue;
+        }
+        let mut c = *c;
+        if ignore_diacritics {
+            c = strip_diacritic(c);
+        }
+        if ignore_case {
+            let mut lower = c.to_lowercase();
+            // 小写为多个字符时（如 "İ"）保持原样
+            if let (Some(x), None) = (lower.next(), lower.next()) {
+                c = x;
+            }
+        }
+        folded.push(c);
+        index.push(i);
+    }
+    (folded, index)
+}
+
+/// 关键词在 text 中出现的位置 [start, end)，为字符下标
+fn keyword_ranges(text: &[char], keyword: &[char]) -> Vec<(usize, usize)> {
+    let mut ranges = vec![];
+    if keyword.is_empty() || keyword.len() > text.len() {
+        return ranges;
+    }
+    let mut i = 0;
+    while i + keyword.len() <= text.len() {
+        if text[i..i + keyword.len()] == *keyword {
+            ranges.push((i, i + keyword.len()));
+            i += keyword.len();
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+/// 正则匹配的位置，使用浏览器的 RegExp，非 wasm 环境或正则无效时为空
+fn pattern_ranges(text: &[char], pattern: &str, ignore_case: bool) -> Vec<(usize, usize)> {
+    if !cfg!(target_arch = "wasm32") || pattern.is_empty() {
+        return vec![];
+    }
+    let flags = if ignore_case { "giu" } else { "gu" };
+    let constructor = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("RegExp"))
+        .ok()
+        .and_then(|x| x.dyn_into::<js_sys::Function>().ok());
+    let regex = constructor
+        .and_then(|f| {
+            js_sys::Reflect::construct(&f, &js_sys::Array::of2(&pattern.into(), &flags.into())).ok()
+        })
+        .and_then(|x| x.dyn_into::<js_sys::RegExp>().ok());
+    let regex = match regex {
+        Some(regex) => regex,
+        None => return vec![],
+    };
+    // RegExp 返回 UTF-16 下标，转为字符下标
+    let mut utf16 = vec![0];
+    for c in text {
+        utf16.push(utf16.last().unwrap_or(&0) + c.len_utf16());
+    }
+    let to_char = |x: usize| utf16.iter().position(|y| *y >= x).unwrap_or(text.len());
+    let source = text.iter().collect::<String>();
+    let mut ranges = vec![];
+    while let Some(m) = regex.exec(&source) {
+        let start = js_sys::Reflect::get(&m, &JsValue::from_str("index"))
+            .ok()
+            .and_then(|x| x.as_f64())
+            .unwrap_or(0.0) as usize;
+        let len = m
+            .get(0)
+            .as_string()
+            .map(|x| x.encode_utf16().count())
+            .unwrap_or(0);
+        if len == 0 {
+            // 空匹配时手动前进，避免死循环
+            regex.set_last_index(regex.last_index() + 1);
+            continue;
+        }
+        ranges.push((to_char(start), to_char(start + len)));
+    }
+    ranges
+}
+
+/// 匹配的区间，已排序并合并重叠部分，为原文中的字符下标
+/// with_pattern 为 false 时不使用正则
+fn highlight_ranges(
+    text: &[char],
+    props: &HighlightProps,
+    with_pattern: bool,
+) -> Vec<(usize, usize)> {
+    let pattern = if with_pattern {
+        props.pattern.as_str()
+    } else {
+        ""
+    };
+    let (folded, index) = fold_text(text, props.ignore_case, props.ignore_diacritics);
+    let mut ranges = props
+        .keywords
+        .iter()
+        .flat_map(|keyword| {
+            let keyword = keyword.chars().collect::<Vec<char>>();
+            let (keyword, _) = fold_text(&keyword, props.ignore_case, props.ignore_diacritics);
+            keyword_ranges(&folded, &keyword)
+        })
+        .chain(pattern_ranges(&folded, pattern, props.ignore_case))
+        .map(|(start, end)| {
+            // 转回原文的下标，结尾包含被忽略的组合符号
+            let mut end = index[end - 1] + 1;
+            while end < text.len() && is_combining(text[end]) {
+                end += 1;
+            }
+            (index[start], end)
+        })
+        .collect::<Vec<(usize, usize)>>();
+    ranges.sort();
+    let mut merged: Vec<(usize, usize)> = vec![];
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct HighlightProps {
+    pub text: String,
+    #[prop_or(vec![])]
+    pub keywords: Vec<String>,
+    #[prop_or(String::from(""))]
+    pub pattern: String,
+    #[prop_or(true)]
+    pub ignore_case: bool,
+    #[prop_or(false)]
+    pub ignore_diacritics: bool,
+    #[prop_or(String::from("inherit"))]
+    pub color: String,
+    #[prop_or(String::from("#fde68a"))]
+    pub bg_color: String,
+    #[prop_or(FontWeight::Inherit)]
+    pub font_weight: FontWeight,
+    #[prop_or(String::from(""))]
+    pub d_color: String,
+    #[prop_or(String::from(""))]
+    pub d_bg_color: String,
+    #[prop_or(String::from(""))]
+    pub testid: String,
+}
+
+/// 高亮文字中的关键词，放在 Text 等组件中使用，其余文字的样式不变
+/// ### 使用示例
+///```text
+/// text: String,
+/// keywords: Vec<String>, // 关键词，可以有多个
+/// pattern: String, // 正则，如 "\\d+"，使用浏览器的 RegExp，挂载后才匹配，SSR 与首次渲染一致
+/// ignore_case: bool, // 忽略大小写，默认 true
+/// ignore_diacritics: bool, // 忽略变音符号，"cafe" 匹配 "café"
+/// color: String, // 高亮的文字颜色，默认继承
+/// bg_color: String, // 高亮的背景色
+/// font_weight: FontWeight,
+/// d_color: String, // dark 模式
+/// d_bg_color: String, // dark 模式
+/// testid: String, // 渲染为 data-testid，在每个高亮的 mark 上
+///```
+#[function_component]
+pub fn Highlight(props: &HighlightProps) -> Html {
+    let style = use_memo(
+        |(color, bg_color, font_weight, d_color, d_bg_color)| {
+            let fallback = |value: &String, base: &String| {
+                if value.is_empty() {
+                    base.clone()
+                } else {
+                    value.clone()
+                }
+            };
+            let blocks = CssBlocks {
+                base: vec![
+                    ("color", color.clone()),
+                    ("background-color", bg_color.clone()),
+                    ("font-weight", font_weight.get_name()),
+                    ("padding", "0".to_owned()),
+                ],
+                hover: vec![],
+                dark: vec![
+                    ("color", fallback(d_color, color)),
+                    ("background-color", fallback(d_bg_color, bg_color)),
+                ],
+            };
+            style_source(blocks.to_css())
+        },
+        (
+            props.color.clone(),
+            props.bg_color.clone(),
+            props.font_weight.clone(),
+            props.d_color.clone(),
+            props.d_bg_color.clone(),
+        ),
+    );
+    let class = (*style).clone();
+
+    // 正则只在浏览器中可用，挂载后再匹配，使 SSR 与首次渲染的结果一致
+    let mounted = use_state_eq(|| false);
+    {
+        let mounted = mounted.clone();
+        use_effect_with_deps(
+            move |_| {
+                mounted.set(true);
+                || ()
+            },
+            (),
+        );
+    }
+
+    let text = props.text.chars().collect::<Vec<char>>();
+    let ranges = highlight_ranges(&text, props, *mounted);
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
+    let mut nodes = vec![];
+    let mut last = 0;
+    for (start, end) in ranges {
+        if start > last {
+            nodes.push(html! { { text[last..start].iter().collect::<String>() } });
+        }
+        nodes.push(html! {
+            <mark class={class.clone()} data-testid={testid.clone()}>
+            { text[start..end].iter().collect::<String>() }
+            </mark>
+        });
+        last = end;
+    }
+    if last < text.len() {
+        nodes.push(html! { { text[last..].iter().collect::<String>() } });
+    }
+    html! { <>{ for nodes }</> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(text: &str, keywords: &[&str], ignore_diacritics: bool) -> Vec<(usize, usize)> {
+        let props = yew::props!(HighlightProps {
+            text: text.to_owned(),
+            keywords: keywords
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>(),
+            ignore_diacritics,
+        });
+        let text = props.text.chars().collect::<Vec<char>>();
+        highlight_ranges(&text, &props, true)
+    }
+
+    #[test]
+    fn diacritics() {
+        // 组合形式 "é"
+        assert_eq!(ranges("Café", &["cafe"], true), vec![(0, 4)]);
+        assert_eq!(ranges("Café", &["cafe"], false), vec![]);
+        // 分解形式 "e\u{301}"，结尾包含组合符号
+        assert_eq!(ranges("Cafe\u{301} noir", &["cafe"], true), vec![(0, 5)]);
+        assert_eq!(ranges("Cafe\u{301} noir", &["café"], true), vec![(0, 5)]);
+        // 不忽略时分解形式也能匹配相同的写法
+        assert_eq!(ranges("Cafe\u{301}", &["cafe\u{301}"], false), vec![(0, 5)]);
+        assert_eq!(ranges("Cafe\u{301}", &["café"], false), vec![]);
+    }
+
+    #[test]
+    fn merge_ranges() {
+        // 重叠
+        assert_eq!(ranges("abcdef", &["abc", "cde"], false), vec![(0, 5)]);
+        // 包含
+        assert_eq!(ranges("abcdef", &["abcdef", "cd"], false), vec![(0, 6)]);
+        // 相邻
+        assert_eq!(ranges("abcdef", &["ab", "cd"], false), vec![(0, 4)]);
+        // 不相交
+        assert_eq!(ranges("ab cd ab", &["ab"], false), vec![(0, 2), (6, 8)]);
+    }
+}
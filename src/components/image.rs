@@ -1,9 +1,115 @@
-use yew::{function_component, html, use_memo, Callback, Html, MouseEvent, NodeRef, Properties};
+use web_sys::{Element, HtmlImageElement};
+use yew::{
+    function_component, html, use_effect_with_deps, use_memo, use_state_eq, Callback, Event, Html,
+    MouseEvent, NodeRef, Properties, TargetCast,
+};
 
-use stylist::css;
+use crate::prelude::{
+    use_breakpoints, use_config, use_design, BorderStyle, Breakpoints, Cursor, Decoding, Design,
+    Duration, ImageFit, ImagePreview, LazyMode, Opacity, Placeholder, PointerEvents, Position,
+    Radius, Size, Spacing, TimingFn, ZIndex,
+};
+use crate::utils::{
+    add_op_space, design_props, is_have_unit, resolved_props, responsive_css, responsive_props,
+    style_source, CssBlocks, IntersectionWatcher,
+};
 
-use crate::prelude::{Cursor, PointerEvents, Position, TimingFn};
-use crate::utils::{add_op_space, is_have_unit};
+/// 骨架屏的闪烁动画
+const SKELETON_KEYFRAMES: &str = "@keyframes yew-quick-skeleton {
+from { background-position: 100% 50%; }
+to { background-position: 0 50%; }
+}
+";
+
+/// 加载完成后的淡入动画，结束时为元素自身的 opacity
+const FADE_KEYFRAMES: &str = "@keyframes yew-quick-fade {
+from { opacity: 0; }
+}
+";
+
+/// `<picture>` 中的 `<source>`，按顺序选择第一个支持的格式与匹配的 media
+/// ImageSource::new("a.avif 1x, a@2x.avif 2x").with_type("image/avif")
+#[derive(Clone, PartialEq, Default)]
+pub struct ImageSource {
+    pub srcset: String,
+    pub mime: String,
+    pub media: String,
+    pub sizes: String,
+}
+impl ImageSource {
+    pub fn new(srcset: &str) -> Self {
+        ImageSource {
+            srcset: srcset.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// "image/avif" "image/webp"
+    pub fn with_type(mut self, mime: &str) -> Self {
+        self.mime = mime.to_owned();
+        self
+    }
+
+    /// "(min-width: 768px)"
+    pub fn with_media(mut self, media: &str) -> Self {
+        self.media = media.to_owned();
+        self
+    }
+
+    pub fn with_sizes(mut self, sizes: &str) -> Self {
+        self.sizes = sizes.to_owned();
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum LoadState {
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// 加载状态，fallback 为 src 加载失败后改用 fallback，之后 fallback 加载完成也不会切回 src
+/// 只在 src 改变时重置
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct LoadStatus {
+    state: LoadState,
+    fallback: bool,
+}
+
+impl LoadStatus {
+    fn new() -> Self {
+        LoadStatus {
+            state: LoadState::Loading,
+            fallback: false,
+        }
+    }
+
+    fn loaded(self) -> Self {
+        LoadStatus {
+            state: LoadState::Loaded,
+            ..self
+        }
+    }
+
+    fn failed(self, has_fallback: bool) -> Self {
+        LoadStatus {
+            state: LoadState::Failed,
+            fallback: self.fallback || has_fallback,
+        }
+    }
+
+    /// img 的 src，未进入可视区域时不设置
+    fn src(&self, visible: bool, src: &str, fallback: &str) -> Option<String> {
+        if !visible {
+            None
+        } else if self.fallback {
+            Some(fallback.to_owned())
+        } else {
+            Some(src.to_owned())
+        }
+    }
+}
 
 #[derive(Clone, PartialEq)]
 struct ImageCss {
@@ -12,7 +118,20 @@ struct ImageCss {
     padding: String,
     margin: String,
     border_radius: String,
+    border_width: String,
+    border_color: String,
+    border_style: String,
+    box_shadow: String,
     cursor: String,
+    object_fit: String,
+    object_position: String,
+    aspect_ratio: String,
+    background_color: String,
+    background_image: String,
+    background_size: String,
+    background_position: String,
+    filter: String,
+    animation: String,
 
     position: String,
     top: String,
@@ -32,15 +151,88 @@ struct ImageCss {
     hover_radius: String,
     hover_width: String,
     hover_height: String,
+    hover_border_width: String,
+    hover_border_color: String,
+    hover_shadow: String,
+    dark_border_color: String,
+    dark_shadow: String,
+    dark_filter: String,
+}
+
+impl ImageCss {
+    fn to_blocks(&self) -> CssBlocks {
+        CssBlocks {
+            base: vec![
+                ("width", self.width.clone()),
+                ("height", self.height.clone()),
+                ("padding", self.padding.clone()),
+                ("margin", self.margin.clone()),
+                ("border-radius", self.border_radius.clone()),
+                ("border-width", self.border_width.clone()),
+                ("border-color", self.border_color.clone()),
+                ("border-style", self.border_style.clone()),
+                ("box-shadow", self.box_shadow.clone()),
+                ("cursor", self.cursor.clone()),
+                ("object-fit", self.object_fit.clone()),
+                ("object-position", self.object_position.clone()),
+                ("aspect-ratio", self.aspect_ratio.clone()),
+                ("background-color", self.background_color.clone()),
+                ("background-image", self.background_image.clone()),
+                ("background-size", self.background_size.clone()),
+                ("background-position", self.background_position.clone()),
+                ("filter", self.filter.clone()),
+                ("animation", self.animation.clone()),
+                ("position", self.position.clone()),
+                ("left", self.left.clone()),
+                ("top", self.top.clone()),
+                ("right", self.right.clone()),
+                ("bottom", self.bottom.clone()),
+                ("z-index", self.z_index.clone()),
+                ("opacity", self.opacity.clone()),
+                ("pointer-events", self.pointer_events.clone()),
+                (
+                    "transition",
+                    format!("all {}s {}", self.duration, self.timing_fn),
+                ),
+            ],
+            hover: vec![
+                ("width", self.hover_width.clone()),
+                ("height", self.hover_height.clone()),
+                ("padding", self.hover_padding.clone()),
+                ("margin", self.hover_margin.clone()),
+                ("border-radius", self.hover_radius.clone()),
+                ("opacity", self.hover_opacity.clone()),
+                ("border-width", self.hover_border_width.clone()),
+                ("border-color", self.hover_border_color.clone()),
+                ("box-shadow", self.hover_shadow.clone()),
+            ],
+            dark: vec![
+                ("border-color", self.dark_border_color.clone()),
+                ("box-shadow", self.dark_shadow.clone()),
+                ("filter", self.dark_filter.clone()),
+            ],
+        }
+    }
 }
 
 #[derive(Clone, PartialEq)]
 struct ImageCssProps {
+    mode: ImageFit,
+    ratio: String,
+    placeholder: Placeholder,
+    fade: String,
+    state: LoadState,
     size: String,
     padding: String,
     margin: String,
 
     radius: String,
+    border_width: String,
+    border_color: String,
+    border_style: BorderStyle,
+    shadow: String,
+    filter: String,
+    aspect_ratio: String,
 
     cursor: Cursor,
 
@@ -61,93 +253,571 @@ struct ImageCssProps {
     h_margin: String,
     h_radius: String,
     h_size: String,
+    h_border_width: String,
+    h_border_color: String,
+    h_shadow: String,
+    d_border_color: String,
+    d_shadow: String,
+    d_brightness: String,
+}
+
+fn get_image_css(box_css_p: &ImageCssProps) -> ImageCss {
+    let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
+    let temp_h_size = if box_css_p.h_size == String::default() {
+        temp_size.clone()
+    } else {
+        box_css_p.h_size.split(" ").collect::<Vec<&str>>()
+    };
+
+    let temp_width = temp_size[0];
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+
+    let temp_h_width = temp_h_size[0];
+    let temp_h_height = if temp_h_size.len() == 1 {
+        temp_h_size[0]
+    } else {
+        temp_h_size[1]
+    };
+
+    let temp_width_op = add_op_space(temp_width);
+    let tmep_width_f = if temp_width.contains("%") {
+        temp_width.to_string()
+    } else {
+        format!("{}{}", temp_width, "px")
+    };
+    let temp_height_op = add_op_space(temp_height);
+    let temp_height_f = if temp_height.contains("%") {
+        temp_height.to_string()
+    } else {
+        format!("{}{}", temp_height, "px")
+    };
+
+    let temp_h_width_op = add_op_space(temp_h_width);
+    let tmep_h_width_f = if temp_h_width.contains("%") {
+        temp_h_width.to_string()
+    } else {
+        format!("{}{}", temp_h_width, "px")
+    };
+    let temp_h_height_op = add_op_space(temp_h_height);
+    let temp_h_height_f = if temp_h_height.contains("%") {
+        temp_h_height.to_string()
+    } else {
+        format!("{}{}", temp_h_height, "px")
+    };
+
+    let temp_padding = box_css_p.padding.as_str();
+    let temp_margin = box_css_p.margin.as_str();
+    let temp_radius = box_css_p.radius.as_str();
+
+    let temp_padding_c = temp_padding
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let padding_value = if is_have_unit(temp_padding) {
+        temp_padding.to_owned()
+    } else {
+        temp_padding_c
+    };
+    let temp_margin_c = temp_margin
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let margin_value = if is_have_unit(temp_margin) {
+        temp_margin.to_owned()
+    } else {
+        temp_margin_c
+    };
+    let temp_radius_c = temp_radius
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || x.contains("/") {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let radius_value = if is_have_unit(temp_radius) {
+        temp_radius.to_owned()
+    } else {
+        temp_radius_c
+    };
+    let border_width_c = |data: &str| {
+        if is_have_unit(data) {
+            data.to_owned()
+        } else {
+            data.split(" ")
+                .map(|x| {
+                    if x.contains("%") || is_have_unit(x) {
+                        x.to_string()
+                    } else {
+                        x.to_string() + "px"
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        }
+    };
+    let border_width_value = border_width_c(&box_css_p.border_width);
+    // 加载完成前元素只显示背景，模糊只作用于低分辨率的占位图
+    let filter_value = match &box_css_p.placeholder {
+        Placeholder::Blur(_) if box_css_p.state == LoadState::Loading => "blur(8px)".to_owned(),
+        _ => box_css_p.filter.clone(),
+    };
+    let (fix_width, fix_height) = match box_css_p.mode {
+        ImageFit::WidthFix => (false, true),
+        ImageFit::HeightFix => (true, false),
+        _ => (false, false),
+    };
+    ImageCss {
+        width: if temp_width == "auto" || fix_width {
+            "auto".to_owned()
+        } else if is_have_unit(temp_width) {
+            temp_width_op
+        } else {
+            tmep_width_f
+        },
+        height: if temp_height == "auto" || fix_height {
+            "auto".to_owned()
+        } else if is_have_unit(temp_height) {
+            temp_height_op
+        } else {
+            temp_height_f
+        },
+        padding: padding_value.clone(),
+        margin: margin_value.clone(),
+        border_radius: radius_value.clone(),
+        border_width: border_width_value.clone(),
+        border_color: box_css_p.border_color.clone(),
+        border_style: box_css_p.border_style.get_name(),
+        box_shadow: box_css_p.shadow.clone(),
+
+        cursor: box_css_p.cursor.get_name(),
+        object_fit: box_css_p.mode.get_name(),
+        object_position: box_css_p.mode.get_position(),
+        aspect_ratio: if (fix_width || fix_height) && box_css_p.ratio != String::default() {
+            box_css_p.ratio.clone()
+        } else {
+            box_css_p.aspect_ratio.clone()
+        },
+        background_color: match &box_css_p.placeholder {
+            Placeholder::Color(color) if box_css_p.state != LoadState::Loaded => color.clone(),
+            Placeholder::Skeleton if box_css_p.state == LoadState::Loading => "#f2f2f2".to_owned(),
+            _ => "transparent".to_owned(),
+        },
+        background_image: match &box_css_p.placeholder {
+            Placeholder::Blur(src) if box_css_p.state == LoadState::Loading => {
+                format!("url({})", src)
+            }
+            Placeholder::Skeleton if box_css_p.state == LoadState::Loading => {
+                "linear-gradient(90deg, #f2f2f2 25%, #e6e6e6 37%, #f2f2f2 63%)".to_owned()
+            }
+            _ => "none".to_owned(),
+        },
+        background_size: match &box_css_p.placeholder {
+            Placeholder::Skeleton => "400% 100%".to_owned(),
+            _ => "cover".to_owned(),
+        },
+        background_position: "center".to_owned(),
+        filter: filter_value.clone(),
+        animation: match &box_css_p.placeholder {
+            Placeholder::Skeleton if box_css_p.state == LoadState::Loading => {
+                "yew-quick-skeleton 1.4s ease infinite".to_owned()
+            }
+            _ if box_css_p.state == LoadState::Loaded && !is_zero(&box_css_p.fade) => format!(
+                "yew-quick-fade {}s {}",
+                box_css_p.fade,
+                box_css_p.timing_fn.get_name()
+            ),
+            _ => "none".to_owned(),
+        },
+        position: box_css_p.position.get_name(),
+        top: if box_css_p.top.contains("%") {
+            box_css_p.top.clone()
+        } else {
+            if box_css_p.top == "auto" || is_have_unit(&box_css_p.top) {
+                box_css_p.top.clone()
+            } else {
+                box_css_p.top.clone() + "px"
+            }
+        },
+        right: if box_css_p.right.contains("%") {
+            box_css_p.right.clone()
+        } else {
+            if box_css_p.right == "auto" || is_have_unit(&box_css_p.right) {
+                box_css_p.right.clone()
+            } else {
+                box_css_p.right.clone() + "px"
+            }
+        },
+        bottom: if box_css_p.bottom.contains("%") {
+            box_css_p.bottom.clone()
+        } else {
+            if box_css_p.bottom == "auto" || is_have_unit(&box_css_p.bottom) {
+                box_css_p.bottom.clone()
+            } else {
+                box_css_p.bottom.clone() + "px"
+            }
+        },
+        left: if box_css_p.left.contains("%") {
+            box_css_p.left.clone()
+        } else {
+            if box_css_p.left == "auto" || is_have_unit(&box_css_p.left) {
+                box_css_p.left.clone()
+            } else {
+                box_css_p.left.clone() + "px"
+            }
+        },
+        z_index: box_css_p.z_index.clone(),
+        opacity: box_css_p.opacity.clone(),
+
+        pointer_events: box_css_p.pointer_events.get_name(),
+
+        duration: box_css_p.duration.clone(),
+        timing_fn: box_css_p.timing_fn.get_name(),
+        hover_opacity: if box_css_p.h_opacity == String::default() {
+            box_css_p.opacity.clone()
+        } else {
+            box_css_p.h_opacity.clone()
+        },
+        hover_padding: if box_css_p.h_padding == String::default() {
+            padding_value
+        } else {
+            let temp = box_css_p.h_padding.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_margin: if box_css_p.h_margin == String::default() {
+            margin_value
+        } else {
+            let temp = box_css_p.h_margin.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_radius: if box_css_p.h_radius == String::default() {
+            radius_value
+        } else {
+            let temp = box_css_p.h_radius.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || x.contains("/") {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_width: if temp_h_width == "auto" || fix_width {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_width) {
+            temp_h_width_op
+        } else {
+            tmep_h_width_f
+        },
+        hover_height: if temp_h_height == "auto" || fix_height {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_height) {
+            temp_h_height_op
+        } else {
+            temp_h_height_f
+        },
+        hover_border_width: if box_css_p.h_border_width == String::default() {
+            border_width_value
+        } else {
+            border_width_c(&box_css_p.h_border_width)
+        },
+        hover_border_color: if box_css_p.h_border_color == String::default() {
+            box_css_p.border_color.clone()
+        } else {
+            box_css_p.h_border_color.clone()
+        },
+        hover_shadow: if box_css_p.h_shadow == String::default() {
+            box_css_p.shadow.clone()
+        } else {
+            box_css_p.h_shadow.clone()
+        },
+        dark_border_color: if box_css_p.d_border_color == String::default() {
+            box_css_p.border_color.clone()
+        } else {
+            box_css_p.d_border_color.clone()
+        },
+        dark_shadow: if box_css_p.d_shadow == String::default() {
+            box_css_p.shadow.clone()
+        } else {
+            box_css_p.d_shadow.clone()
+        },
+        dark_filter: if box_css_p.d_brightness == String::default() {
+            filter_value
+        } else if filter_value == "none" {
+            format!("brightness({})", box_css_p.d_brightness)
+        } else {
+            format!("{} brightness({})", filter_value, box_css_p.d_brightness)
+        },
+    }
 }
 
 #[derive(Properties, Clone, PartialEq)]
 pub struct ImageProps {
     pub src: String,
-    #[prop_or(String::from("auto auto"))]
-    pub size: String,
-    #[prop_or(String::from("0"))]
-    pub padding: String,
-    #[prop_or(String::from("0"))]
-    pub margin: String,
-    #[prop_or(String::from("0"))]
-    pub radius: String,
-
-    #[prop_or(Cursor::Unset)]
-    pub cursor: Cursor,
-
-    #[prop_or(Position::Static)]
-    pub position: Position,
-    #[prop_or(String::from("auto"))]
-    pub top: String,
-    #[prop_or(String::from("auto"))]
-    pub right: String,
-    #[prop_or(String::from("auto"))]
-    pub bottom: String,
-    #[prop_or(String::from("auto"))]
-    pub left: String,
-    #[prop_or(String::from("auto"))]
-    pub z_index: String,
-    #[prop_or(String::from("inherit"))]
-    pub opacity: String,
-
-    #[prop_or(String::from("0"))]
-    pub duration: String,
-    #[prop_or(TimingFn::Ease)]
-    pub timing_fn: TimingFn,
     #[prop_or(String::from(""))]
-    pub h_opacity: String,
+    pub srcset: String,
     #[prop_or(String::from(""))]
-    pub h_padding: String,
+    pub sizes: String,
+    #[prop_or(vec![])]
+    pub sources: Vec<ImageSource>,
+    #[prop_or_default]
+    pub mode: Option<ImageFit>,
+    #[prop_or_default]
+    pub lazy: Option<LazyMode>,
+    #[prop_or_default]
+    pub lazy_margin: Option<String>,
+    #[prop_or_default]
+    pub placeholder: Option<Placeholder>,
+    #[prop_or_default]
+    pub fallback: Option<String>,
+    #[prop_or_default]
+    pub fade: Duration,
+    #[prop_or(false)]
+    pub preview: bool,
+    #[prop_or(vec![])]
+    pub preview_images: Vec<String>,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub padding: Spacing,
+    #[prop_or_default]
+    pub margin: Spacing,
+    #[prop_or_default]
+    pub radius: Radius,
+    #[prop_or_default]
+    pub border_width: Spacing,
+    #[prop_or_default]
+    pub border_color: Option<String>,
+    #[prop_or_default]
+    pub border_style: Option<BorderStyle>,
+    #[prop_or_default]
+    pub shadow: Option<String>,
+    #[prop_or_default]
+    pub filter: Option<String>,
+    #[prop_or_default]
+    pub aspect_ratio: Option<String>,
+
+    #[prop_or_default]
+    pub cursor: Option<Cursor>,
+
+    #[prop_or_default]
+    pub position: Option<Position>,
+    #[prop_or_default]
+    pub top: Option<String>,
+    #[prop_or_default]
+    pub right: Option<String>,
+    #[prop_or_default]
+    pub bottom: Option<String>,
+    #[prop_or_default]
+    pub left: Option<String>,
+    #[prop_or_default]
+    pub z_index: ZIndex,
+    #[prop_or_default]
+    pub opacity: Opacity,
+
+    #[prop_or_default]
+    pub duration: Duration,
+    #[prop_or_default]
+    pub timing_fn: Option<TimingFn>,
+    #[prop_or_default]
+    pub h_opacity: Opacity,
+    #[prop_or_default]
+    pub h_padding: Spacing,
+    #[prop_or_default]
+    pub h_margin: Spacing,
+    #[prop_or_default]
+    pub h_radius: Radius,
+    #[prop_or_default]
+    pub h_size: Size,
+    #[prop_or_default]
+    pub h_border_width: Spacing,
+    #[prop_or_default]
+    pub h_border_color: Option<String>,
+    #[prop_or_default]
+    pub h_shadow: Option<String>,
+
+    #[prop_or_default]
+    pub d_border_color: Option<String>,
+    #[prop_or_default]
+    pub d_shadow: Option<String>,
+    #[prop_or_default]
+    pub d_brightness: Option<String>,
+    #[prop_or(String::from(""))]
+    pub d_src: String,
+
     #[prop_or(String::from(""))]
-    pub h_margin: String,
+    pub alt: String,
+    #[prop_or(true)]
+    pub draggable: bool,
+    #[prop_or_default]
+    pub decoding: Option<Decoding>,
     #[prop_or(String::from(""))]
-    pub h_radius: String,
+    pub cross_origin: String,
+
     #[prop_or(String::from(""))]
-    pub h_size: String,
+    pub testid: String,
 
-    #[prop_or(PointerEvents::Auto)]
-    pointer_events: PointerEvents,
+    #[prop_or_default]
+    pub pointer_events: Option<PointerEvents>,
 
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
     #[prop_or_default]
+    pub onload: Callback<(u32, u32)>,
+    #[prop_or_default]
+    pub onerror: Callback<String>,
+    #[prop_or_default]
     pub node: NodeRef,
 }
 
-/// ### 使用示例
-///```
-/// size: String,
-/// padding: String,
-/// margin: String,
-/// radius: String,
-/// cursor: Cursor,
-/// position: Position,
-/// top: String,
-/// right: String,
-/// bottom: String,
-/// left: String,
-/// z_index: String,
-/// opacity: String,
-/// pointer_events: PointerEvents,
-/// duration: String,
-/// timing_fn: TimingFn, // transition 的动画方式
-/// h_size: String,
-/// h_opacity: String,  //hover 样式 "0.7"
-/// h_padding: String,  //hover 样式 "0 0 12 12"
-/// h_margin: String,  //hover 样式 "0 0 12 12"
-/// h_radius: String,    //hover 样式 "12"
-///```
-///
-#[function_component]
-pub fn Image(props: &ImageProps) -> Html {
-    let box_css_p: ImageCssProps = ImageCssProps {
-        size: props.size.clone(),
-        padding: props.padding.clone(),
-        margin: props.margin.clone(),
-        radius: props.radius.clone(),
+resolved_props!(
+    ResolvedImageProps for ImageProps {
+        mode: ImageFit = ImageFit::ScaleToFill,
+        lazy: LazyMode = LazyMode::None,
+        lazy_margin: String = String::from("200px"),
+        placeholder: Placeholder = Placeholder::None,
+        fallback: String = String::from(""),
+        fade: Duration = Duration::from("0"),
+        size: Size = Size::from("auto auto"),
+        padding: Spacing = Spacing::from("0"),
+        margin: Spacing = Spacing::from("0"),
+        radius: Radius = Radius::from("0"),
+        border_width: Spacing = Spacing::from("0"),
+        border_color: String = String::from("transparent"),
+        border_style: BorderStyle = BorderStyle::No,
+        shadow: String = String::from("none"),
+        filter: String = String::from("none"),
+        aspect_ratio: String = String::from("auto"),
+        cursor: Cursor = Cursor::Unset,
+        position: Position = Position::Static,
+        top: String = String::from("auto"),
+        right: String = String::from("auto"),
+        bottom: String = String::from("auto"),
+        left: String = String::from("auto"),
+        z_index: ZIndex = ZIndex::from("auto"),
+        opacity: Opacity = Opacity::from("inherit"),
+        duration: Duration = Duration::from("0"),
+        timing_fn: TimingFn = TimingFn::Ease,
+        h_opacity: Opacity = Opacity::from(""),
+        h_padding: Spacing = Spacing::from(""),
+        h_margin: Spacing = Spacing::from(""),
+        h_radius: Radius = Radius::from(""),
+        h_size: Size = Size::from(""),
+        h_border_width: Spacing = Spacing::from(""),
+        h_border_color: String = String::from(""),
+        h_shadow: String = String::from(""),
+        d_border_color: String = String::from(""),
+        d_shadow: String = String::from(""),
+        d_brightness: String = String::from(""),
+        decoding: Decoding = Decoding::Auto,
+        pointer_events: PointerEvents = PointerEvents::Auto,
+    }
+    src: String,
+    srcset: String,
+    sizes: String,
+    sources: Vec<ImageSource>,
+    preview: bool,
+    preview_images: Vec<String>,
+    d_src: String,
+    alt: String,
+    draggable: bool,
+    cross_origin: String,
+    testid: String,
+    onclick: Callback<MouseEvent>,
+    onload: Callback<(u32, u32)>,
+    onerror: Callback<String>,
+    node: NodeRef,
+);
+
+/// 组件生成的 css，可在原生环境中调用（如测试）
+/// 断点、设计稿换算使用默认值，不含 ConfigProvider
+pub fn image_css(props: &ImageProps) -> String {
+    build_image_css(
+        &get_image_css_props(&resolve_image_props(props, None)),
+        &Breakpoints::default(),
+        &Design::default(),
+    )
+}
+
+/// 依次使用内置默认值、ConfigProvider、组件中设置的属性
+fn resolve_image_props(props: &ImageProps, config: Option<&ImageProps>) -> ResolvedImageProps {
+    let mut p = ResolvedImageProps::new(props, config);
+    p.apply(props);
+    p
+}
+
+fn get_image_css_props(props: &ResolvedImageProps) -> ImageCssProps {
+    ImageCssProps {
+        mode: props.mode.clone(),
+        ratio: String::new(),
+        placeholder: props.placeholder.clone(),
+        fade: props.fade.to_string(),
+        state: LoadState::Loading,
+        size: props.size.to_string(),
+        padding: props.padding.to_string(),
+        margin: props.margin.to_string(),
+        radius: props.radius.to_string(),
+        border_width: props.border_width.to_string(),
+        border_color: props.border_color.clone(),
+        border_style: props.border_style.clone(),
+        shadow: props.shadow.clone(),
+        filter: props.filter.clone(),
+        aspect_ratio: props.aspect_ratio.clone(),
         cursor: props.cursor.clone(),
 
         position: props.position.clone(),
@@ -155,325 +825,348 @@ pub fn Image(props: &ImageProps) -> Html {
         right: props.right.clone(),
         bottom: props.bottom.clone(),
         left: props.left.clone(),
-        z_index: props.z_index.clone(),
-        opacity: props.opacity.clone(),
+        z_index: props.z_index.to_string(),
+        opacity: props.opacity.to_string(),
 
         pointer_events: props.pointer_events.clone(),
 
-        duration: props.duration.clone(),
+        duration: props.duration.to_string(),
         timing_fn: props.timing_fn.clone(),
-        h_opacity: props.h_opacity.clone(),
-        h_padding: props.h_padding.clone(),
-        h_margin: props.h_margin.clone(),
-        h_radius: props.h_radius.clone(),
-        h_size: props.h_size.clone(),
-    };
-
-    let box_css = use_memo(
-        |box_css_p| {
-            let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
-            let temp_h_size = if box_css_p.h_size == String::default() {
-                temp_size.clone()
-            } else {
-                box_css_p.h_size.split(" ").collect::<Vec<&str>>()
-            };
-
-            let temp_width = temp_size[0];
-            let temp_height = if temp_size.len() == 1 {
-                temp_size[0]
-            } else {
-                temp_size[1]
-            };
-
-            let temp_h_width = temp_h_size[0];
-            let temp_h_height = if temp_h_size.len() == 1 {
-                temp_h_size[0]
-            } else {
-                temp_h_size[1]
-            };
-
-            let temp_width_op = add_op_space(temp_width);
-            let tmep_width_f = if temp_width.contains("%") {
-                format!("{}", temp_width)
-            } else {
-                format!("{}{}", temp_width, "px")
-            };
-            let temp_height_op = add_op_space(temp_height);
-            let temp_height_f = if temp_height.contains("%") {
-                format!("{}", temp_height)
-            } else {
-                format!("{}{}", temp_height, "px")
-            };
+        h_opacity: props.h_opacity.to_string(),
+        h_padding: props.h_padding.to_string(),
+        h_margin: props.h_margin.to_string(),
+        h_radius: props.h_radius.to_string(),
+        h_size: props.h_size.to_string(),
+        h_border_width: props.h_border_width.to_string(),
+        h_border_color: props.h_border_color.clone(),
+        h_shadow: props.h_shadow.clone(),
+        d_border_color: props.d_border_color.clone(),
+        d_shadow: props.d_shadow.clone(),
+        d_brightness: props.d_brightness.clone(),
+    }
+}
 
-            let temp_h_width_op = add_op_space(temp_h_width);
-            let tmep_h_width_f = if temp_h_width.contains("%") {
-                format!("{}", temp_h_width)
-            } else {
-                format!("{}{}", temp_h_width, "px")
-            };
-            let temp_h_height_op = add_op_space(temp_h_height);
-            let temp_h_height_f = if temp_h_height.contains("%") {
-                format!("{}", temp_h_height)
-            } else {
-                format!("{}{}", temp_h_height, "px")
-            };
+fn build_image_css(
+    box_css_p: &ImageCssProps,
+    breakpoints: &Breakpoints,
+    design: &Design,
+) -> String {
+    let box_css_p = &design_props!(
+        box_css_p,
+        design,
+        size,
+        padding,
+        margin,
+        radius,
+        border_width,
+        top,
+        right,
+        bottom,
+        left,
+        h_padding,
+        h_margin,
+        h_radius,
+        h_size,
+        h_border_width
+    );
+    let (base, points) = responsive_props!(
+        box_css_p,
+        breakpoints,
+        size,
+        padding,
+        margin,
+        radius,
+        top,
+        right,
+        bottom,
+        left,
+        z_index,
+        opacity,
+        duration,
+        h_opacity,
+        h_padding,
+        h_margin,
+        h_radius,
+        h_size,
+        border_width,
+        border_color,
+        shadow,
+        filter,
+        aspect_ratio,
+        h_border_width,
+        h_border_color,
+        h_shadow,
+        d_border_color,
+        d_shadow,
+        d_brightness,
+    );
+    let points = points
+        .iter()
+        .map(|(name, p)| (name.clone(), get_image_css(p).to_blocks()))
+        .collect();
+    let mut css = responsive_css(&get_image_css(&base).to_blocks(), points, breakpoints);
+    if box_css_p.placeholder == Placeholder::Skeleton && box_css_p.state == LoadState::Loading {
+        css += SKELETON_KEYFRAMES;
+    }
+    if box_css_p.state == LoadState::Loaded && !is_zero(&box_css_p.fade) {
+        css += FADE_KEYFRAMES;
+    }
+    css
+}
 
-            let temp_padding = box_css_p.padding.as_str();
-            let temp_margin = box_css_p.margin.as_str();
-            let temp_radius = box_css_p.radius.as_str();
+fn is_zero(duration: &str) -> bool {
+    duration.parse::<f64>().map(|x| x == 0.0).unwrap_or(false)
+}
 
-            let temp_padding_c = temp_padding
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let padding_value = if is_have_unit(temp_padding) {
-                temp_padding.to_owned()
-            } else {
-                temp_padding_c
-            };
-            let temp_margin_c = temp_margin
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let margin_value = if is_have_unit(temp_margin) {
-                temp_margin.to_owned()
-            } else {
-                temp_margin_c
-            };
-            let temp_radius_c = temp_radius
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") || x.contains("/") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
+/// ### 使用示例
+///```text
+/// src: String,
+/// srcset: String, // "a.jpg 1x, a@2x.jpg 2x" 或 "a-480.jpg 480w, a-960.jpg 960w"
+/// sizes: String, // "(max-width: 768px) 100vw, 50vw"
+/// sources: Vec<ImageSource>, // 不为空时渲染为 <picture>，如 vec![ImageSource::new("a.avif").with_type("image/avif")]
+/// mode: ImageFit, // 同小程序 image 的 mode，WidthFix / HeightFix 按图片原始宽高比计算另一边
+/// size: Size,
+/// padding: Spacing,
+/// margin: Spacing,
+/// radius: Radius,
+/// border_width: Spacing,
+/// border_color: String,
+/// border_style: BorderStyle,
+/// shadow: String,
+/// filter: String, // "grayscale(1)"
+/// aspect_ratio: String, // "16 / 9"
+/// cursor: Cursor,
+/// position: Position,
+/// top: String,
+/// right: String,
+/// bottom: String,
+/// left: String,
+/// z_index: ZIndex,
+/// opacity: Opacity,
+/// pointer_events: PointerEvents,
+/// duration: Duration,
+/// timing_fn: TimingFn, // transition 的动画方式
+/// h_size: Size,
+/// h_opacity: Opacity,  //hover 样式 "0.7"
+/// h_padding: Spacing,  //hover 样式 "0 0 12 12"
+/// h_margin: Spacing,  //hover 样式 "0 0 12 12"
+/// h_radius: Radius,    //hover 样式 "12"
+/// h_border_width: Spacing,  //hover 样式
+/// h_border_color: String,  //hover 样式
+/// h_shadow: String,  //hover 样式
+/// d_border_color: String, // dark 模式
+/// d_shadow: String, // dark 模式
+/// d_brightness: String, // dark 模式下的亮度 "0.8"
+/// d_src: String, // dark 模式下使用的图片
+/// alt: String,
+/// draggable: bool, // false 时禁止拖动图片
+/// decoding: Decoding,
+/// cross_origin: String, // "anonymous"，跨域图片需要绘制到 canvas 时使用
+/// lazy: LazyMode, // 懒加载，Observer 进入可视区域后加载，Native 使用 loading="lazy"
+/// lazy_margin: String, // Observer 提前加载的距离，同 css margin "200px"
+/// placeholder: Placeholder, // 加载完成前的占位，Color("#eee") Blur("small.jpg") Skeleton
+/// fallback: String, // 加载失败时使用的图片
+/// fade: Duration, // 加载完成后淡入的时间
+/// preview: bool, // 点击后全屏预览
+/// preview_images: Vec<String>, // 预览时可左右切换的图片，默认只有 src
+/// onload: Callback<(u32, u32)>, // 加载完成，图片的原始宽高
+/// onerror: Callback<String>, // 加载失败，失败的图片地址
+/// testid: String, // 渲染为 data-testid
+///```
+///
+#[function_component]
+pub fn Image(props: &ImageProps) -> Html {
+    let config = use_config();
+    let props = &resolve_image_props(props, config.image_props.as_deref());
+    let node = props.node.clone();
+    let visible = use_state_eq(|| props.lazy != LazyMode::Observer);
+    let status = use_state_eq(LoadStatus::new);
+    // WidthFix / HeightFix 时，图片加载后记录原始宽高
+    let natural = use_state_eq(|| None::<(u32, u32)>);
 
-            let radius_value = if is_have_unit(temp_radius) {
-                temp_radius.to_owned()
-            } else {
-                temp_radius_c
-            };
-            ImageCss {
-                width: if temp_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_width) {
-                    temp_width_op
+    {
+        let node = node.clone();
+        let set_visible = visible.clone();
+        use_effect_with_deps(
+            move |(lazy, lazy_margin, is_visible)| {
+                if *lazy != LazyMode::Observer {
+                    set_visible.set(true);
+                }
+                let watcher = if *lazy == LazyMode::Observer && !is_visible {
+                    node.cast::<Element>().map(|el| {
+                        IntersectionWatcher::new(&el, lazy_margin, 0.0, move |entry| {
+                            if entry.is_intersecting() {
+                                set_visible.set(true);
+                            }
+                        })
+                    })
                 } else {
-                    tmep_width_f
-                },
-                height: if temp_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_height) {
-                    temp_height_op
-                } else {
-                    temp_height_f
-                },
-                padding: padding_value.clone(),
-                margin: margin_value.clone(),
-                border_radius: radius_value.clone(),
-
-                cursor: box_css_p.cursor.get_name(),
-                position: box_css_p.position.get_name(),
-                top: if box_css_p.top.contains("%") {
-                    box_css_p.top.clone()
-                } else {
-                    if box_css_p.top == String::from("auto") {
-                        box_css_p.top.clone()
-                    } else {
-                        box_css_p.top.clone() + "px"
-                    }
-                },
-                right: if box_css_p.right.contains("%") {
-                    box_css_p.right.clone()
-                } else {
-                    if box_css_p.right == String::from("auto") {
-                        box_css_p.right.clone()
-                    } else {
-                        box_css_p.right.clone() + "px"
-                    }
-                },
-                bottom: if box_css_p.bottom.contains("%") {
-                    box_css_p.bottom.clone()
-                } else {
-                    if box_css_p.bottom == String::from("auto") {
-                        box_css_p.bottom.clone()
-                    } else {
-                        box_css_p.bottom.clone() + "px"
-                    }
-                },
-                left: if box_css_p.left.contains("%") {
-                    box_css_p.left.clone()
-                } else {
-                    if box_css_p.left == String::from("auto") {
-                        box_css_p.left.clone()
-                    } else {
-                        box_css_p.left.clone() + "px"
+                    None
+                };
+                move || drop(watcher)
+            },
+            (props.lazy.clone(), props.lazy_margin.clone(), *visible),
+        );
+    }
+    {
+        // src 改变时重新加载，已缓存（如 SSR 后 hydrate）的图片可能在绑定 onload 前就已完成
+        let node = node.clone();
+        let status = status.clone();
+        use_effect_with_deps(
+            move |_| {
+                status.set(LoadStatus::new());
+                if let Some(img) = node.cast::<HtmlImageElement>() {
+                    if img.complete() && img.natural_width() > 0 {
+                        status.set(LoadStatus::new().loaded());
                     }
-                },
-                z_index: box_css_p.z_index.clone(),
-                opacity: box_css_p.opacity.clone(),
+                }
+            },
+            (props.src.clone(), *visible),
+        );
+    }
+    let onload = {
+        let natural = natural.clone();
+        let status = status.clone();
+        let onload = props.onload.clone();
+        Callback::from(move |e: Event| {
+            let img: HtmlImageElement = e.target_unchecked_into();
+            let (w, h) = (img.natural_width(), img.natural_height());
+            natural.set((w > 0 && h > 0).then_some((w, h)));
+            status.set((*status).loaded());
+            onload.emit((w, h));
+        })
+    };
+    let onerror = {
+        let status = status.clone();
+        let onerror = props.onerror.clone();
+        let has_fallback = props.fallback != String::default();
+        Callback::from(move |e: Event| {
+            let img: HtmlImageElement = e.target_unchecked_into();
+            status.set((*status).failed(has_fallback));
+            onerror.emit(img.get_attribute("src").unwrap_or_default());
+        })
+    };
+    // 使用 fallback 时不再使用 srcset 和 sources
+    let use_fallback = status.fallback;
+    let src = status.src(*visible, &props.src, &props.fallback);
+    // 未进入可视区域或使用 fallback 时不渲染，空值不渲染
+    let lazy_attr = |value: &String| {
+        (*visible && !use_fallback && value != &String::default()).then(|| value.clone())
+    };
+    let srcset = lazy_attr(&props.srcset);
+    let sizes = lazy_attr(&props.sizes);
+    let loading = (props.lazy == LazyMode::Native).then_some("lazy");
+    let alt = (!props.alt.is_empty()).then(|| props.alt.clone());
+    let draggable = (!props.draggable).then_some("false");
+    let decoding = (props.decoding != Decoding::Auto).then(|| props.decoding.get_name());
+    let crossorigin = (!props.cross_origin.is_empty()).then(|| props.cross_origin.clone());
 
-                pointer_events: box_css_p.pointer_events.get_name(),
+    let mut box_css_p = get_image_css_props(props);
+    box_css_p.state = status.state;
+    if let (ImageFit::WidthFix | ImageFit::HeightFix, Some((w, h))) = (&props.mode, *natural) {
+        box_css_p.ratio = format!("{} / {}", w, h);
+    }
 
-                duration: box_css_p.duration.clone(),
-                timing_fn: box_css_p.timing_fn.get_name(),
-                hover_opacity: if box_css_p.h_opacity == String::default() {
-                    box_css_p.opacity.clone()
-                } else {
-                    box_css_p.h_opacity.clone()
-                },
-                hover_padding: if box_css_p.h_padding == String::default() {
-                    padding_value
-                } else {
-                    let temp = box_css_p.h_padding.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_margin: if box_css_p.h_margin == String::default() {
-                    margin_value
-                } else {
-                    let temp = box_css_p.h_margin.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_radius: if box_css_p.h_radius == String::default() {
-                    radius_value
-                } else {
-                    let temp = box_css_p.h_radius.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") || x.contains("/") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_width: if temp_h_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_width) {
-                    temp_h_width_op
-                } else {
-                    tmep_h_width_f
-                },
-                hover_height: if temp_h_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_height) {
-                    temp_h_height_op
-                } else {
-                    temp_h_height_f
-                },
-            }
+    let breakpoints = use_breakpoints();
+    let design = use_design();
+    let style = use_memo(
+        |(box_css_p, breakpoints, design)| {
+            style_source(build_image_css(box_css_p, breakpoints, design))
         },
-        box_css_p,
+        (box_css_p, breakpoints, design),
     );
+    let class = (*style).clone();
 
-    let class = css!(
-        r#"
-            width: ${width};
-            height: ${height};
-            padding: ${padding};
-            margin: ${margin};
-            border-radius: ${radius};
-
-
-            cursor: ${cursor};
-
+    let picture_class = use_memo(|_| style_source("display: contents;".to_owned()), ());
 
-            position: ${position};
-            left: ${left};
-            top: ${top};
-            right: ${right};
-            bottom: ${bottom};
-            z-index: ${z_index};
-            opacity: ${opacity};
+    let preview_open = use_state_eq(|| false);
+    let onclick = {
+        let preview_open = preview_open.clone();
+        let onclick = props.onclick.clone();
+        let preview = props.preview;
+        Callback::from(move |e: MouseEvent| {
+            if preview {
+                preview_open.set(true);
+            }
+            onclick.emit(e);
+        })
+    };
+    let preview = if props.preview {
+        let images = if props.preview_images.is_empty() {
+            vec![props.src.clone()]
+        } else {
+            props.preview_images.clone()
+        };
+        let index = images.iter().position(|x| x == &props.src).unwrap_or(0);
+        let on_close = {
+            let preview_open = preview_open.clone();
+            Callback::from(move |_| preview_open.set(false))
+        };
+        html! {
+            <ImagePreview {images} {index} open={*preview_open} {on_close} />
+        }
+    } else {
+        html! {}
+    };
 
-            pointer-events: ${pointer_events};
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
+    let img = html! {
+        <img {class} data-testid={testid} {onclick} {onload} {onerror} {src} {srcset} {sizes} {loading} {alt} {draggable} {decoding} {crossorigin} ref={node}/>
+    };
+    if (props.sources.is_empty() && props.d_src.is_empty()) || use_fallback {
+        return html! {
+            <>
+            { img }
+            { preview }
+            </>
+        };
+    }
+    html! {
+        <>
+        <picture class={(*picture_class).clone()}>
+        if !props.d_src.is_empty() {
+            <source srcset={lazy_attr(&props.d_src)} media="(prefers-color-scheme: dark)" />
+        }
+        { for props.sources.iter().map(|source| html! {
+            <source
+                srcset={lazy_attr(&source.srcset)}
+                type={lazy_attr(&source.mime)}
+                media={lazy_attr(&source.media)}
+                sizes={lazy_attr(&source.sizes)}
+            />
+        }) }
+        { img }
+        </picture>
+        { preview }
+        </>
+    }
+}
 
-            transition: all ${duration}s ${timing_fn};
+#[cfg(test)]
+mod tests {
+    use super::{LoadState, LoadStatus};
 
-            &:hover {
-                width: ${hover_width};
-                height: ${hover_height};
-                padding: ${hover_padding};
-                margin: ${hover_margin};
-                border-radius: ${hover_radius};
-                opacity: ${hover_opacity};
-            }
-        "#,
-        width = box_css.width,
-        height = box_css.height,
-        padding = box_css.padding,
-        margin = box_css.margin,
-        radius = box_css.border_radius,
-        cursor = box_css.cursor,
-        position = box_css.position,
-        top = box_css.top,
-        right = box_css.right,
-        bottom = box_css.bottom,
-        left = box_css.left,
-        z_index = box_css.z_index,
-        opacity = box_css.opacity,
-        duration = box_css.duration,
-        timing_fn = box_css.timing_fn,
-        hover_opacity = box_css.hover_opacity,
-        hover_padding = box_css.hover_padding,
-        hover_margin = box_css.hover_margin,
-        hover_radius = box_css.hover_radius,
-        pointer_events = box_css.pointer_events,
-        hover_width = box_css.hover_width,
-        hover_height = box_css.hover_height,
-    );
+    #[test]
+    fn fallback_is_kept_after_load() {
+        let status = LoadStatus::new();
+        assert_eq!(status.src(true, "a.png", "b.png").as_deref(), Some("a.png"));
+        // src 加载失败，改用 fallback
+        let status = status.failed(true);
+        assert_eq!(status.src(true, "a.png", "b.png").as_deref(), Some("b.png"));
+        // fallback 加载完成后不会切回 src
+        let status = status.loaded();
+        assert_eq!(status.state, LoadState::Loaded);
+        assert_eq!(status.src(true, "a.png", "b.png").as_deref(), Some("b.png"));
+        // fallback 也失败时保持 fallback，不再请求 src
+        let status = status.failed(true);
+        assert_eq!(status.src(true, "a.png", "b.png").as_deref(), Some("b.png"));
+    }
 
-    html! {
-        <img {class} onclick={props.onclick.clone()} src={props.src.clone()} ref={props.node.clone()}/>
+    #[test]
+    fn no_fallback() {
+        let status = LoadStatus::new().failed(false);
+        assert_eq!(status.state, LoadState::Failed);
+        assert_eq!(status.src(true, "a.png", "").as_deref(), Some("a.png"));
+        assert_eq!(LoadStatus::new().src(false, "a.png", "b.png"), None);
     }
 }
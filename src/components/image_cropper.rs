@@ -0,0 +1,701 @@
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{Blob, CanvasRenderingContext2d, Element, HtmlCanvasElement, HtmlImageElement};
+use yew::{
+    function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref, use_state_eq,
+    Callback, Html, MouseEvent, NodeRef, Properties, TouchEvent, WheelEvent,
+};
+
+use crate::prelude::{Box, Image, Size};
+use crate::utils::style_source;
+
+/// 图片加载后，裁剪框占图片的比例
+const INIT_RATIO: f64 = 0.8;
+
+/// 裁剪区域，单位为原图的 px，区域相对于旋转 rotate 度后的图片
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct CropArea {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub rotate: f64,
+}
+
+/// 拖动的对象，Handle 为裁剪框的角，0 ~ 3 依次为 左上 右上 右下 左下
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Image,
+    Crop,
+    Handle(usize),
+}
+
+/// 手势的输入，触摸与鼠标事件都转为 Action 处理
+enum Action {
+    Start(Target, f64, f64),
+    Move(f64, f64),
+    PinchStart(f64),
+    Pinch(f64),
+    End,
+    Wheel(f64),
+}
+
+/// 裁剪的状态，坐标相对于容器（px）
+#[derive(Clone, Copy, PartialEq, Default)]
+struct View {
+    container: (f64, f64),
+    natural: (f64, f64),
+    /// scale 为 1 时，图片完整显示在容器中的缩放
+    fit: f64,
+    scale: f64,
+    /// 图片中心相对容器中心的偏移
+    offset: (f64, f64),
+    /// 裁剪框 x y width height
+    rect: (f64, f64, f64, f64),
+    rotate: f64,
+}
+
+impl View {
+    /// 旋转后图片的外接矩形 x y width height
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let (w, h) = (
+            self.natural.0 * self.fit * self.scale,
+            self.natural.1 * self.fit * self.scale,
+        );
+        let (sin, cos) = self.rotate.to_radians().sin_cos();
+        let (bw, bh) = (
+            (w * cos).abs() + (h * sin).abs(),
+            (w * sin).abs() + (h * cos).abs(),
+        );
+        (
+            self.container.0 / 2.0 + self.offset.0 - bw / 2.0,
+            self.container.1 / 2.0 + self.offset.1 - bh / 2.0,
+            bw,
+            bh,
+        )
+    }
+
+    /// 裁剪框可以移动的范围，为容器与图片的交集 left top right bottom
+    fn limit(&self) -> (f64, f64, f64, f64) {
+        let (bx, by, bw, bh) = self.bounds();
+        (
+            bx.max(0.0),
+            by.max(0.0),
+            (bx + bw).min(self.container.0),
+            (by + bh).min(self.container.1),
+        )
+    }
+
+    /// 缩放、拖动图片后，保证图片覆盖裁剪框
+    fn clamp_image(&mut self) {
+        let (_, _, bw, bh) = self.bounds();
+        let (_, _, w, h) = self.rect;
+        if bw > 0.0 && bh > 0.0 {
+            self.scale *= (w / bw).max(h / bh).max(1.0);
+        }
+        let (_, _, bw, bh) = self.bounds();
+        let (x, y, w, h) = self.rect;
+        let (cx, cy) = (self.container.0 / 2.0, self.container.1 / 2.0);
+        // 不用 clamp，浮点误差可能使下限略大于上限
+        self.offset = (
+            self.offset
+                .0
+                .max(x + w - cx - bw / 2.0)
+                .min(x - cx + bw / 2.0),
+            self.offset
+                .1
+                .max(y + h - cy - bh / 2.0)
+                .min(y - cy + bh / 2.0),
+        );
+    }
+
+    /// 图片加载或比例改变后，裁剪框居中
+    fn init_rect(&mut self, aspect: f64) {
+        let (left, top, right, bottom) = self.limit();
+        let (lw, lh) = (right - left, bottom - top);
+        let (w, h) = if aspect > 0.0 {
+            let w = lw.min(lh * aspect) * INIT_RATIO;
+            (w, w / aspect)
+        } else {
+            (lw * INIT_RATIO, lh * INIT_RATIO)
+        };
+        self.rect = (left + (lw - w) / 2.0, top + (lh - h) / 2.0, w, h);
+    }
+
+    fn area(&self) -> CropArea {
+        let (bx, by, _, _) = self.bounds();
+        let (x, y, w, h) = self.rect;
+        let k = self.fit * self.scale;
+        if k <= 0.0 {
+            return CropArea::default();
+        }
+        CropArea {
+            x: (x - bx) / k,
+            y: (y - by) / k,
+            width: w / k,
+            height: h / k,
+            rotate: self.rotate,
+        }
+    }
+}
+
+/// 手势过程中的数据，view 以这里为准，state 只用于渲染
+struct Gesture {
+    target: Option<Target>,
+    moved: bool,
+    start: (f64, f64),
+    start_view: View,
+    start_distance: f64,
+    aspect: f64,
+    view: View,
+}
+
+impl Default for Gesture {
+    fn default() -> Self {
+        Gesture {
+            target: None,
+            moved: false,
+            start: (0.0, 0.0),
+            start_view: View::default(),
+            start_distance: 1.0,
+            aspect: 0.0,
+            view: View::default(),
+        }
+    }
+}
+
+/// 获取 ImageCropper 的裁剪结果，同 NodeRef 一样传给组件的 cropper 属性
+/// ### 使用示例
+///```text
+/// let cropper = use_state(CropperRef::default);
+/// <ImageCropper src="avatar.jpg" aspect={1.0} cropper={(*cropper).clone()} />
+/// let url = cropper.to_data_url(200, 200, "image/png", 1.0);
+/// cropper.to_blob(200, 200, "image/jpeg", 0.9, Callback::from(|blob: Option<Blob>| ()));
+///```
+#[derive(Clone, Default)]
+pub struct CropperRef(Rc<RefCell<Option<(NodeRef, View)>>>);
+
+impl PartialEq for CropperRef {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl CropperRef {
+    /// 当前的裁剪区域，图片未加载时为 None
+    pub fn area(&self) -> Option<CropArea> {
+        match &*self.0.borrow() {
+            Some((_, view)) if view.fit > 0.0 => Some(view.area()),
+            _ => None,
+        }
+    }
+
+    /// 将裁剪区域绘制到 width * height 的 canvas 上，canvas 不会插入页面
+    /// width 或 height 为 0 时按裁剪框的比例计算，都为 0 时为裁剪区域在原图中的大小
+    pub fn to_canvas(&self, width: u32, height: u32) -> Option<HtmlCanvasElement> {
+        let (image, view) = self.0.borrow().clone()?;
+        let img = image.cast::<HtmlImageElement>()?;
+        let (x, y, w, h) = view.rect;
+        if view.fit <= 0.0 || w <= 0.0 || h <= 0.0 {
+            return None;
+        }
+        let area = view.area();
+        let (width, height) = match (width, height) {
+            (0, 0) => (area.width.round() as u32, area.height.round() as u32),
+            (0, height) => ((height as f64 * w / h).round() as u32, height),
+            (width, 0) => (width, (width as f64 * h / w).round() as u32),
+            size => size,
+        };
+        let canvas = web_sys::window()?
+            .document()?
+            .create_element("canvas")
+            .ok()?
+            .dyn_into::<HtmlCanvasElement>()
+            .ok()?;
+        canvas.set_width(width.max(1));
+        canvas.set_height(height.max(1));
+        let ctx = canvas
+            .get_context("2d")
+            .ok()??
+            .dyn_into::<CanvasRenderingContext2d>()
+            .ok()?;
+        // 与页面上的变换相同：移动到图片中心，旋转，缩放
+        let k = view.fit * view.scale;
+        ctx.scale(width as f64 / w, height as f64 / h).ok()?;
+        ctx.translate(
+            view.container.0 / 2.0 + view.offset.0 - x,
+            view.container.1 / 2.0 + view.offset.1 - y,
+        )
+        .ok()?;
+        ctx.rotate(view.rotate.to_radians()).ok()?;
+        ctx.scale(k, k).ok()?;
+        ctx.draw_image_with_html_image_element(&img, -view.natural.0 / 2.0, -view.natural.1 / 2.0)
+            .ok()?;
+        Some(canvas)
+    }
+
+    /// 裁剪结果的 data url，mime 如 "image/png" "image/jpeg"，quality 为 0 ~ 1
+    /// 跨域图片需要设置 cross_origin，否则 canvas 无法导出
+    pub fn to_data_url(&self, width: u32, height: u32, mime: &str, quality: f64) -> Option<String> {
+        self.to_canvas(width, height)?
+            .to_data_url_with_type_and_encoder_options(mime, &JsValue::from_f64(quality))
+            .ok()
+    }
+
+    /// 裁剪结果的 Blob，可直接用于上传，失败时 callback 收到 None
+    pub fn to_blob(
+        &self,
+        width: u32,
+        height: u32,
+        mime: &str,
+        quality: f64,
+        callback: Callback<Option<Blob>>,
+    ) {
+        let canvas = match self.to_canvas(width, height) {
+            Some(canvas) => canvas,
+            None => return callback.emit(None),
+        };
+        let emit = callback.clone();
+        let closure = Closure::once_into_js(move |blob: JsValue| {
+            emit.emit(blob.dyn_into::<Blob>().ok());
+        });
+        if canvas
+            .to_blob_with_type_and_encoder_options(
+                closure.unchecked_ref(),
+                mime,
+                &JsValue::from_f64(quality),
+            )
+            .is_err()
+        {
+            callback.emit(None);
+        }
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ImageCropperProps {
+    pub src: String,
+    #[prop_or(Size::from("100% 300"))]
+    pub size: Size,
+    #[prop_or(0.0)]
+    pub aspect: f64,
+    #[prop_or(0.0)]
+    pub rotate: f64,
+    #[prop_or(3.0)]
+    pub max_scale: f64,
+    #[prop_or(40.0)]
+    pub min_size: f64,
+    #[prop_or(false)]
+    pub round: bool,
+    #[prop_or(true)]
+    pub guides: bool,
+    #[prop_or(String::from("rgba(0, 0, 0, 0.5)"))]
+    pub mask_color: String,
+    #[prop_or(String::from("#000"))]
+    pub bg_color: String,
+    #[prop_or(String::from(""))]
+    pub cross_origin: String,
+    #[prop_or(String::from(""))]
+    pub testid: String,
+    #[prop_or_default]
+    pub cropper: CropperRef,
+    #[prop_or_default]
+    pub on_change: Callback<CropArea>,
+}
+
+/// 图片裁剪，拖动、缩放裁剪框选择区域，拖动图片、双指或滚轮缩放图片
+/// 通过 CropperRef 获取裁剪结果的 data url 或 Blob
+/// ### 使用示例
+///```text
+/// src: String,
+/// size: Size, // 组件的大小，默认 "100% 300"
+/// aspect: f64, // 裁剪框的宽高比，如 1.0，0 为不限制
+/// rotate: f64, // 图片旋转的角度，如 90.0
+/// max_scale: f64, // 图片最大缩放倍数，默认 3
+/// min_size: f64, // 裁剪框的最小边长（px），默认 40
+/// round: bool, // 圆形裁剪框（如头像），导出的图片仍为矩形
+/// guides: bool, // 是否显示九宫格参考线，默认 true
+/// mask_color: String, // 裁剪框外的遮罩颜色
+/// bg_color: String, // 背景色，默认 "#000"
+/// cross_origin: String, // 跨域图片需要设置为 "anonymous" 才能导出
+/// testid: String, // 渲染为 data-testid
+/// cropper: CropperRef, // 获取裁剪结果
+/// on_change: Callback<CropArea>, // 裁剪区域改变，单位为原图的 px
+///```
+#[function_component]
+pub fn ImageCropper(props: &ImageCropperProps) -> Html {
+    let node = use_node_ref();
+    let image = use_node_ref();
+    let view = use_state_eq(View::default);
+    let gesture = use_mut_ref(Gesture::default);
+    *props.cropper.0.borrow_mut() = Some((image.clone(), *view));
+
+    {
+        // 比例改变时重新放置裁剪框，旋转后保证图片覆盖裁剪框
+        let view = view.clone();
+        let gesture = gesture.clone();
+        let on_change = props.on_change.clone();
+        use_effect_with_deps(
+            move |(aspect, rotate)| {
+                let mut g = gesture.borrow_mut();
+                if g.view.fit > 0.0 {
+                    g.view.rotate = *rotate;
+                    if g.aspect != *aspect {
+                        g.view.scale = 1.0;
+                        g.view.offset = (0.0, 0.0);
+                        g.view.init_rect(*aspect);
+                    }
+                    g.view.clamp_image();
+                    view.set(g.view);
+                    on_change.emit(g.view.area());
+                }
+                g.aspect = *aspect;
+                || ()
+            },
+            (props.aspect, props.rotate),
+        );
+    }
+
+    let onload = {
+        let node = node.clone();
+        let view = view.clone();
+        let gesture = gesture.clone();
+        let on_change = props.on_change.clone();
+        let aspect = props.aspect;
+        let rotate = props.rotate;
+        Callback::from(move |(w, h): (u32, u32)| {
+            let container = match node.cast::<Element>() {
+                Some(el) => (el.client_width() as f64, el.client_height() as f64),
+                None => return,
+            };
+            if w == 0 || h == 0 {
+                return;
+            }
+            let natural = (w as f64, h as f64);
+            let mut v = View {
+                container,
+                natural,
+                fit: (container.0 / natural.0).min(container.1 / natural.1),
+                scale: 1.0,
+                offset: (0.0, 0.0),
+                rect: (0.0, 0.0, 0.0, 0.0),
+                rotate,
+            };
+            // 旋转后外接矩形变大，缩小到容器内
+            let (_, _, bw, bh) = v.bounds();
+            v.fit *= (container.0 / bw).min(container.1 / bh).min(1.0);
+            v.init_rect(aspect);
+            let mut g = gesture.borrow_mut();
+            g.view = v;
+            g.aspect = aspect;
+            view.set(v);
+            on_change.emit(v.area());
+        })
+    };
+
+    let dispatch = {
+        let view = view.clone();
+        let gesture = gesture.clone();
+        let on_change = props.on_change.clone();
+        let aspect = props.aspect;
+        let max_scale = props.max_scale.max(1.0);
+        let min_size = props.min_size.max(1.0);
+        Callback::from(move |action: Action| {
+            let mut g = gesture.borrow_mut();
+            if g.view.fit <= 0.0 {
+                return;
+            }
+            match action {
+                Action::Start(target, x, y) => {
+                    g.target = Some(target);
+                    g.moved = false;
+                    g.start = (x, y);
+                    g.start_view = g.view;
+                }
+                Action::Move(x, y) => {
+                    let target = match g.target {
+                        Some(target) => target,
+                        None => return,
+                    };
+                    g.moved = true;
+                    let (dx, dy) = (x - g.start.0, y - g.start.1);
+                    let start = g.start_view;
+                    let (sx, sy, sw, sh) = start.rect;
+                    let mut v = start;
+                    match target {
+                        Target::Image => {
+                            v.offset = (start.offset.0 + dx, start.offset.1 + dy);
+                            v.clamp_image();
+                        }
+                        Target::Crop => {
+                            let (left, top, right, bottom) = start.limit();
+                            v.rect = (
+                                (sx + dx).clamp(left, (right - sw).max(left)),
+                                (sy + dy).clamp(top, (bottom - sh).max(top)),
+                                sw,
+                                sh,
+                            );
+                        }
+                        Target::Handle(i) => {
+                            // 对角固定，拖动的角跟随手指
+                            let (right, bottom) = (i == 1 || i == 2, i >= 2);
+                            let anchor = (
+                                if right { sx } else { sx + sw },
+                                if bottom { sy } else { sy + sh },
+                            );
+                            let corner = (
+                                if right { sx + sw + dx } else { sx + dx },
+                                if bottom { sy + sh + dy } else { sy + dy },
+                            );
+                            let (left_l, top_l, right_l, bottom_l) = start.limit();
+                            let max_w = if right {
+                                right_l - anchor.0
+                            } else {
+                                anchor.0 - left_l
+                            };
+                            let max_h = if bottom {
+                                bottom_l - anchor.1
+                            } else {
+                                anchor.1 - top_l
+                            };
+                            let mut w = (corner.0 - anchor.0).abs().max(min_size);
+                            let mut h = (corner.1 - anchor.1).abs().max(min_size);
+                            if aspect > 0.0 {
+                                w = w.max(h * aspect).min(max_w).min(max_h * aspect);
+                                h = w / aspect;
+                            } else {
+                                w = w.min(max_w);
+                                h = h.min(max_h);
+                            }
+                            v.rect = (
+                                if right { anchor.0 } else { anchor.0 - w },
+                                if bottom { anchor.1 } else { anchor.1 - h },
+                                w,
+                                h,
+                            );
+                        }
+                    }
+                    g.view = v;
+                    view.set(v);
+                }
+                Action::PinchStart(distance) => {
+                    g.target = None;
+                    g.moved = true;
+                    g.start_distance = distance.max(1.0);
+                    g.start_view = g.view;
+                }
+                Action::Pinch(distance) => {
+                    let start = g.start_view;
+                    let scale = (start.scale * distance / g.start_distance).min(max_scale);
+                    g.view.scale = scale;
+                    // 以容器中心缩放
+                    g.view.offset = (
+                        start.offset.0 * scale / start.scale,
+                        start.offset.1 * scale / start.scale,
+                    );
+                    g.view.clamp_image();
+                    view.set(g.view);
+                }
+                Action::Wheel(delta) => {
+                    let old = g.view.scale;
+                    let scale = (old * (-delta * 0.002).exp()).min(max_scale);
+                    g.view.scale = scale;
+                    g.view.offset = (g.view.offset.0 * scale / old, g.view.offset.1 * scale / old);
+                    g.view.clamp_image();
+                    view.set(g.view);
+                    on_change.emit(g.view.area());
+                }
+                Action::End => {
+                    let moved = g.moved;
+                    g.target = None;
+                    g.moved = false;
+                    if moved {
+                        on_change.emit(g.view.area());
+                    }
+                }
+            }
+        })
+    };
+
+    let touch_distance = |e: &TouchEvent| {
+        let touches = e.touches();
+        match (touches.get(0), touches.get(1)) {
+            (Some(a), Some(b)) => {
+                let dx = (a.client_x() - b.client_x()) as f64;
+                let dy = (a.client_y() - b.client_y()) as f64;
+                Some((dx * dx + dy * dy).sqrt())
+            }
+            _ => None,
+        }
+    };
+    // 裁剪框和四个角阻止冒泡，容器上的按下视为拖动图片
+    let ontouchstart = |target: Target| {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: TouchEvent| {
+            e.stop_propagation();
+            match touch_distance(&e) {
+                Some(distance) => dispatch.emit(Action::PinchStart(distance)),
+                None => {
+                    if let Some(t) = e.touches().get(0) {
+                        dispatch.emit(Action::Start(
+                            target,
+                            t.client_x() as f64,
+                            t.client_y() as f64,
+                        ));
+                    }
+                }
+            }
+        })
+    };
+    let onmousedown = |target: Target| {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            e.stop_propagation();
+            dispatch.emit(Action::Start(
+                target,
+                e.client_x() as f64,
+                e.client_y() as f64,
+            ));
+        })
+    };
+    let ontouchmove = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: TouchEvent| match touch_distance(&e) {
+            Some(distance) => dispatch.emit(Action::Pinch(distance)),
+            None => {
+                if let Some(t) = e.touches().get(0) {
+                    dispatch.emit(Action::Move(t.client_x() as f64, t.client_y() as f64));
+                }
+            }
+        })
+    };
+    let ontouchend = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: TouchEvent| {
+            if e.touches().length() == 0 {
+                dispatch.emit(Action::End);
+            }
+        })
+    };
+    let onmousemove = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: MouseEvent| {
+            dispatch.emit(Action::Move(e.client_x() as f64, e.client_y() as f64))
+        })
+    };
+    let onmouseup = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |_: MouseEvent| dispatch.emit(Action::End))
+    };
+    let onwheel = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: WheelEvent| dispatch.emit(Action::Wheel(e.delta_y())))
+    };
+
+    let class = style_source(format!(
+        r#"
+            position: relative;
+            width: 100%;
+            height: 100%;
+            overflow: hidden;
+            background-color: {bg_color};
+            touch-action: none;
+            user-select: none;
+
+            .image-cropper-image {{
+                position: absolute;
+                left: 50%;
+                top: 50%;
+                transform-origin: center;
+            }}
+            .image-cropper-box {{
+                position: absolute;
+                box-sizing: border-box;
+                border: 1px solid rgba(255, 255, 255, 0.8);
+                box-shadow: 0 0 0 9999px {mask_color};
+                cursor: move;
+            }}
+            .image-cropper-guide {{
+                position: absolute;
+                border: 0 dashed rgba(255, 255, 255, 0.5);
+                pointer-events: none;
+            }}
+            .image-cropper-handle {{
+                position: absolute;
+                width: 16px;
+                height: 16px;
+                margin: -8px;
+                border: 2px solid #fff;
+                box-sizing: border-box;
+                background-color: rgba(0, 0, 0, 0.3);
+            }}
+        "#,
+        bg_color = props.bg_color.clone(),
+        mask_color = props.mask_color.clone(),
+    ));
+
+    let loaded = view.fit > 0.0;
+    let image_style = if loaded {
+        format!(
+            "width: {}px; height: {}px; transform: translate(-50%, -50%) translate({}px, {}px) rotate({}deg) scale({});",
+            view.natural.0 * view.fit,
+            view.natural.1 * view.fit,
+            view.offset.0,
+            view.offset.1,
+            view.rotate,
+            view.scale
+        )
+    } else {
+        String::from(
+            "width: 100%; height: 100%; transform: translate(-50%, -50%); visibility: hidden;",
+        )
+    };
+    let (x, y, w, h) = view.rect;
+    let box_style = format!(
+        "left: {}px; top: {}px; width: {}px; height: {}px; border-radius: {};",
+        x,
+        y,
+        w,
+        h,
+        if props.round { "50%" } else { "0" }
+    );
+    let handle_style = |i: usize| {
+        format!(
+            "left: {}; top: {}; cursor: {};",
+            if i == 1 || i == 2 { "100%" } else { "0" },
+            if i >= 2 { "100%" } else { "0" },
+            // 左上、右下沿对角线方向
+            if i == 0 || i == 2 {
+                "nwse-resize"
+            } else {
+                "nesw-resize"
+            }
+        )
+    };
+
+    html! {
+        <Box size={props.size.clone()} testid={props.testid.clone()}>
+            <div {class} ref={node}
+                ontouchstart={ontouchstart(Target::Image)} {ontouchmove} ontouchend={ontouchend.clone()} ontouchcancel={ontouchend}
+                onmousedown={onmousedown(Target::Image)} {onmousemove} onmouseup={onmouseup.clone()} onmouseleave={onmouseup} {onwheel}
+            >
+                <div class="image-cropper-image" style={image_style}>
+                    <Image src={props.src.clone()} size="100% 100%" draggable={false} cross_origin={props.cross_origin.clone()} node={image} {onload} />
+                </div>
+                if loaded {
+                    <div class="image-cropper-box" style={box_style} ontouchstart={ontouchstart(Target::Crop)} onmousedown={onmousedown(Target::Crop)}>
+                        if props.guides {
+                            <div class="image-cropper-guide" style="left: 0; top: 33.33%; width: 100%; height: 33.33%; border-top-width: 1px; border-bottom-width: 1px;" />
+                            <div class="image-cropper-guide" style="left: 33.33%; top: 0; width: 33.33%; height: 100%; border-left-width: 1px; border-right-width: 1px;" />
+                        }
+                        { for (0..4).map(|i| html! {
+                            <div class="image-cropper-handle" style={handle_style(i)} ontouchstart={ontouchstart(Target::Handle(i))} onmousedown={onmousedown(Target::Handle(i))} />
+                        }) }
+                    </div>
+                }
+            </div>
+        </Box>
+    }
+}
@@ -0,0 +1,480 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlImageElement, KeyboardEvent};
+use yew::{
+    function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref, use_state_eq,
+    Callback, Html, MouseEvent, Properties, TouchEvent, WheelEvent,
+};
+
+use crate::prelude::{Duration, Portal};
+use crate::utils::{style_source, WindowEventWatcher};
+
+/// 两次点击间隔小于该值（ms）时视为双击
+const DOUBLE_TAP_TIME: f64 = 300.0;
+/// 移动超过该距离（px）时不再视为点击
+const TAP_MOVE: f64 = 6.0;
+/// 双击放大的倍数
+const DOUBLE_TAP_SCALE: f64 = 2.0;
+/// 触摸后该时间（ms）内的鼠标事件为浏览器模拟的兼容事件，忽略
+const TOUCH_MOUSE_TIME: f64 = 500.0;
+
+/// 手势的输入，触摸与鼠标事件都转为 Action 处理
+enum Action {
+    Start(f64, f64),
+    Move(f64, f64),
+    PinchStart(f64),
+    Pinch(f64),
+    End,
+    Wheel(f64),
+}
+
+/// 手势过程中的数据，scale 和 offset 以这里为准，state 只用于渲染
+struct Gesture {
+    pressed: bool,
+    moved: bool,
+    start: (f64, f64),
+    start_offset: (f64, f64),
+    start_distance: f64,
+    start_scale: f64,
+    scale: f64,
+    offset: (f64, f64),
+    swipe: f64,
+    last_tap: f64,
+    last_touch: f64,
+}
+
+impl Default for Gesture {
+    fn default() -> Self {
+        Gesture {
+            pressed: false,
+            moved: false,
+            start: (0.0, 0.0),
+            start_offset: (0.0, 0.0),
+            start_distance: 1.0,
+            start_scale: 1.0,
+            scale: 1.0,
+            offset: (0.0, 0.0),
+            swipe: 0.0,
+            last_tap: 0.0,
+            last_touch: 0.0,
+        }
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ImagePreviewProps {
+    pub images: Vec<String>,
+    #[prop_or(false)]
+    pub open: bool,
+    #[prop_or(0)]
+    pub index: usize,
+    #[prop_or(3.0)]
+    pub max_scale: f64,
+    #[prop_or(true)]
+    pub indicator: bool,
+    #[prop_or(String::from("rgba(0, 0, 0, 0.9)"))]
+    pub bg_color: String,
+    #[prop_or(Duration::from("0.3"))]
+    pub duration: Duration,
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub on_change: Callback<usize>,
+}
+
+/// 全屏预览图片，双指或滚轮缩放，放大后拖动查看，双击切换缩放，左右滑动切换图片
+/// 点击背景或按 Escape 关闭，左右方向键切换图片
+/// ### 使用示例
+///```text
+/// images: Vec<String>, // 图片列表
+/// open: bool,
+/// index: usize, // 打开时显示的图片
+/// max_scale: f64, // 最大缩放倍数，默认 3
+/// indicator: bool, // 是否显示 "1 / 3"，默认 true
+/// bg_color: String, // 背景色，默认 "rgba(0, 0, 0, 0.9)"
+/// duration: Duration, // 切换图片的动画时间
+/// on_close: Callback<()>,
+/// on_change: Callback<usize>, // 切换图片
+///```
+#[function_component]
+pub fn ImagePreview(props: &ImagePreviewProps) -> Html {
+    let node = use_node_ref();
+    let index = use_state_eq(|| props.index);
+    let scale = use_state_eq(|| 1.0_f64);
+    let offset = use_state_eq(|| (0.0_f64, 0.0_f64));
+    let swipe = use_state_eq(|| 0.0_f64);
+    let dragging = use_state_eq(|| false);
+    let gesture = use_mut_ref(Gesture::default);
+    // 供 window 事件读取当前的图片
+    let current = use_mut_ref(|| props.index);
+    *current.borrow_mut() = *index;
+
+    let count = props.images.len();
+    let reset_zoom = {
+        let scale = scale.clone();
+        let offset = offset.clone();
+        let gesture = gesture.clone();
+        Callback::from(move |_: ()| {
+            let mut g = gesture.borrow_mut();
+            g.scale = 1.0;
+            g.offset = (0.0, 0.0);
+            scale.set(1.0);
+            offset.set((0.0, 0.0));
+        })
+    };
+    // 通过 current 读取当前的图片，window 事件中保存的 go 不会过期
+    let go = {
+        let index = index.clone();
+        let current = current.clone();
+        let reset_zoom = reset_zoom.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |i: usize| {
+            if i < count && i != *current.borrow() {
+                *current.borrow_mut() = i;
+                index.set(i);
+                reset_zoom.emit(());
+                on_change.emit(i);
+            }
+        })
+    };
+
+    {
+        // 打开时显示 props.index
+        let index = index.clone();
+        let reset_zoom = reset_zoom.clone();
+        use_effect_with_deps(
+            move |(open, start)| {
+                if *open {
+                    index.set((*start).min(count.saturating_sub(1)));
+                    reset_zoom.emit(());
+                }
+                || ()
+            },
+            (props.open, props.index),
+        );
+    }
+    {
+        let on_close = props.on_close.clone();
+        let go = go.clone();
+        let current = current.clone();
+        use_effect_with_deps(
+            move |(open, _)| {
+                let watcher = if *open {
+                    WindowEventWatcher::new("keydown", move |e| {
+                        let i = *current.borrow();
+                        match e.unchecked_into::<KeyboardEvent>().key().as_str() {
+                            "Escape" => on_close.emit(()),
+                            "ArrowLeft" if i > 0 => go.emit(i - 1),
+                            "ArrowRight" => go.emit(i + 1),
+                            _ => {}
+                        }
+                    })
+                } else {
+                    None
+                };
+                move || drop(watcher)
+            },
+            (props.open, count),
+        );
+    }
+
+    let dispatch = {
+        let node = node.clone();
+        let scale = scale.clone();
+        let offset = offset.clone();
+        let swipe = swipe.clone();
+        let dragging = dragging.clone();
+        let gesture = gesture.clone();
+        let go = go.clone();
+        let i = *index;
+        let max_scale = props.max_scale.max(1.0);
+        Callback::from(move |action: Action| {
+            let (width, height) = node
+                .cast::<Element>()
+                .map(|el| (el.client_width() as f64, el.client_height() as f64))
+                .unwrap_or((0.0, 0.0));
+            // 放大后可拖动的范围
+            let clamp = |(x, y): (f64, f64), s: f64| {
+                let (max_x, max_y) = ((s - 1.0) * width / 2.0, (s - 1.0) * height / 2.0);
+                (x.clamp(-max_x, max_x), y.clamp(-max_y, max_y))
+            };
+            let mut g = gesture.borrow_mut();
+            match action {
+                Action::Start(x, y) => {
+                    g.pressed = true;
+                    g.moved = false;
+                    g.start = (x, y);
+                    g.start_offset = g.offset;
+                    dragging.set(true);
+                }
+                Action::Move(x, y) => {
+                    if !g.pressed {
+                        return;
+                    }
+                    let (dx, dy) = (x - g.start.0, y - g.start.1);
+                    if dx.abs() + dy.abs() > TAP_MOVE {
+                        g.moved = true;
+                    }
+                    if g.scale > 1.0 {
+                        g.offset = clamp((g.start_offset.0 + dx, g.start_offset.1 + dy), g.scale);
+                        offset.set(g.offset);
+                    } else {
+                        // 第一张和最后一张时阻尼
+                        let edge = (i == 0 && dx > 0.0) || (i + 1 >= count && dx < 0.0);
+                        g.swipe = if edge { dx / 3.0 } else { dx };
+                        swipe.set(g.swipe);
+                    }
+                }
+                Action::PinchStart(distance) => {
+                    g.pressed = false;
+                    g.moved = true;
+                    g.start_distance = distance.max(1.0);
+                    g.start_scale = g.scale;
+                    dragging.set(true);
+                    g.swipe = 0.0;
+                    swipe.set(0.0);
+                }
+                Action::Pinch(distance) => {
+                    g.scale = (g.start_scale * distance / g.start_distance).clamp(1.0, max_scale);
+                    g.offset = clamp(g.offset, g.scale);
+                    scale.set(g.scale);
+                    offset.set(g.offset);
+                }
+                Action::Wheel(delta) => {
+                    g.scale = (g.scale * (-delta * 0.002).exp()).clamp(1.0, max_scale);
+                    g.offset = clamp(g.offset, g.scale);
+                    scale.set(g.scale);
+                    offset.set(g.offset);
+                }
+                Action::End => {
+                    dragging.set(false);
+                    if !g.pressed {
+                        return;
+                    }
+                    g.pressed = false;
+                    if g.moved {
+                        let dx = g.swipe;
+                        g.swipe = 0.0;
+                        swipe.set(0.0);
+                        if g.scale <= 1.0 && dx.abs() > (width * 0.2).min(80.0) {
+                            if dx < 0.0 {
+                                go.emit(i + 1);
+                            } else if i > 0 {
+                                go.emit(i - 1);
+                            }
+                        }
+                        return;
+                    }
+                    let now = js_sys::Date::now();
+                    if now - g.last_tap < DOUBLE_TAP_TIME {
+                        g.last_tap = 0.0;
+                        g.scale = if g.scale > 1.0 {
+                            1.0
+                        } else {
+                            DOUBLE_TAP_SCALE.min(max_scale)
+                        };
+                        g.offset = (0.0, 0.0);
+                        scale.set(g.scale);
+                        offset.set(g.offset);
+                    } else {
+                        g.last_tap = now;
+                    }
+                }
+            }
+        })
+    };
+
+    let touch_distance = |e: &TouchEvent| {
+        let touches = e.touches();
+        match (touches.get(0), touches.get(1)) {
+            (Some(a), Some(b)) => {
+                let dx = (a.client_x() - b.client_x()) as f64;
+                let dy = (a.client_y() - b.client_y()) as f64;
+                Some((dx * dx + dy * dy).sqrt())
+            }
+            _ => None,
+        }
+    };
+    let ontouchstart = {
+        let dispatch = dispatch.clone();
+        let gesture = gesture.clone();
+        Callback::from(move |e: TouchEvent| {
+            gesture.borrow_mut().last_touch = js_sys::Date::now();
+            match touch_distance(&e) {
+                Some(distance) => dispatch.emit(Action::PinchStart(distance)),
+                None => {
+                    if let Some(t) = e.touches().get(0) {
+                        dispatch.emit(Action::Start(t.client_x() as f64, t.client_y() as f64));
+                    }
+                }
+            }
+        })
+    };
+    let ontouchmove = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: TouchEvent| match touch_distance(&e) {
+            Some(distance) => dispatch.emit(Action::Pinch(distance)),
+            None => {
+                if let Some(t) = e.touches().get(0) {
+                    dispatch.emit(Action::Move(t.client_x() as f64, t.client_y() as f64));
+                }
+            }
+        })
+    };
+    let ontouchend = {
+        let dispatch = dispatch.clone();
+        let gesture = gesture.clone();
+        Callback::from(move |e: TouchEvent| {
+            gesture.borrow_mut().last_touch = js_sys::Date::now();
+            match e.touches().get(0) {
+                // 双指抬起一指后，继续用剩下的手指拖动，且不视为点击
+                Some(t) if e.touches().length() == 1 => {
+                    dispatch.emit(Action::Start(t.client_x() as f64, t.client_y() as f64));
+                    gesture.borrow_mut().moved = true;
+                }
+                Some(_) => {}
+                None => dispatch.emit(Action::End),
+            }
+        })
+    };
+    // 点击后浏览器会模拟 mousedown mouseup，不忽略时一次点击会被视为双击
+    let is_touch_mouse = {
+        let gesture = gesture.clone();
+        move || js_sys::Date::now() - gesture.borrow().last_touch < TOUCH_MOUSE_TIME
+    };
+    let onmousedown = {
+        let dispatch = dispatch.clone();
+        let is_touch_mouse = is_touch_mouse.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            if !is_touch_mouse() {
+                dispatch.emit(Action::Start(e.client_x() as f64, e.client_y() as f64));
+            }
+        })
+    };
+    let onmousemove = {
+        let dispatch = dispatch.clone();
+        let is_touch_mouse = is_touch_mouse.clone();
+        Callback::from(move |e: MouseEvent| {
+            if !is_touch_mouse() {
+                dispatch.emit(Action::Move(e.client_x() as f64, e.client_y() as f64))
+            }
+        })
+    };
+    let onmouseup = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !is_touch_mouse() {
+                dispatch.emit(Action::End)
+            }
+        })
+    };
+    let onwheel = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |e: WheelEvent| dispatch.emit(Action::Wheel(e.delta_y())))
+    };
+    let onclick = {
+        let gesture = gesture.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |e: MouseEvent| {
+            // 点击图片以外的区域关闭，拖动后的点击不关闭
+            let on_image = e
+                .target()
+                .map(|t| t.dyn_into::<HtmlImageElement>().is_ok())
+                .unwrap_or(false);
+            if !on_image && !gesture.borrow().moved {
+                on_close.emit(());
+            }
+        })
+    };
+
+    if !props.open {
+        return html! {};
+    }
+
+    let class = style_source(format!(
+        r#"
+            position: fixed;
+            top: 0;
+            left: 0;
+            width: 100vw;
+            height: 100vh;
+            overflow: hidden;
+            background-color: {bg_color};
+            touch-action: none;
+            user-select: none;
+
+            .image-preview-track {{
+                display: flex;
+                width: 100%;
+                height: 100%;
+                transition-property: transform;
+            }}
+            .image-preview-slot {{
+                flex-shrink: 0;
+                width: 100%;
+                height: 100%;
+                display: flex;
+                justify-content: center;
+                align-items: center;
+                overflow: hidden;
+            }}
+            .image-preview-img {{
+                max-width: 100%;
+                max-height: 100%;
+                object-fit: contain;
+                transition-property: transform;
+            }}
+            .image-preview-indicator {{
+                position: absolute;
+                left: 0;
+                bottom: calc(24px + env(safe-area-inset-bottom));
+                width: 100%;
+                text-align: center;
+                font-size: 14px;
+                color: #fff;
+            }}
+        "#,
+        bg_color = props.bg_color.clone(),
+    ));
+
+    let duration = if *dragging {
+        String::from("0")
+    } else {
+        props.duration.to_string()
+    };
+    let track_style = format!(
+        "transform: translate3d(calc({}% + {}px), 0, 0); transition-duration: {}s;",
+        -(*index as f64) * 100.0,
+        *swipe,
+        duration
+    );
+    let img_style = format!(
+        "transform: translate3d({}px, {}px, 0) scale({}); transition-duration: {}s;",
+        offset.0, offset.1, *scale, duration
+    );
+
+    html! {
+        <Portal>
+            <div {class} ref={node} {onclick} {ontouchstart} {ontouchmove} ontouchend={ontouchend.clone()} ontouchcancel={ontouchend} {onmousedown} {onmousemove} onmouseup={onmouseup.clone()} onmouseleave={onmouseup} {onwheel}>
+                <div class="image-preview-track" style={track_style}>
+                { for props.images.iter().enumerate().map(|(i, src)| html! {
+                    <div class="image-preview-slot">
+                    // 只加载当前及相邻的图片
+                    if i + 1 >= *index && i <= *index + 1 {
+                        <img
+                            class="image-preview-img"
+                            src={src.clone()}
+                            draggable="false"
+                            style={if i == *index { img_style.clone() } else { String::new() }}
+                        />
+                    }
+                    </div>
+                }) }
+                </div>
+                if props.indicator && count > 1 {
+                    <div class="image-preview-indicator">{ format!("{} / {}", *index + 1, count) }</div>
+                }
+            </div>
+        </Portal>
+    }
+}
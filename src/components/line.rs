@@ -1,17 +1,21 @@
 use yew::{function_component, html, use_memo, Html, Properties};
 
-use stylist::css;
-
-use crate::prelude::{Cursor, SafeType, TimingFn};
-use crate::utils::{add_op_space, is_have_unit};
+use crate::prelude::{
+    use_breakpoints, use_config, use_design, Breakpoints, Cursor, Design, Duration, Opacity,
+    Radius, SafeType, Size, Spacing, TimingFn,
+};
+use crate::utils::{
+    add_op_space, design_props, is_have_unit, resolved_props, responsive_css, responsive_props,
+    safe_area_value, style_source, CssBlocks,
+};
 
 #[derive(Clone, PartialEq)]
 struct LineCss {
     width: String,
     height: String,
     margin: String,
-    padding_top: String,
-    padding_bottom: String,
+    padding: String,
+    padding_constant: String,
     border_radius: String,
     background_color: String,
     background_image: String,
@@ -33,9 +37,42 @@ struct LineCss {
     dark_bg_color: String,
 }
 
+impl LineCss {
+    fn to_blocks(&self) -> CssBlocks {
+        CssBlocks {
+            base: vec![
+                ("width", self.width.clone()),
+                ("height", self.height.clone()),
+                ("padding", self.padding_constant.clone()),
+                ("padding", self.padding.clone()),
+                ("margin", self.margin.clone()),
+                ("border-radius", self.border_radius.clone()),
+                ("background-color", self.background_color.clone()),
+                ("background-image", self.background_image.clone()),
+                ("cursor", self.cursor.clone()),
+                ("flex-shrink", self.flex_shrink.clone()),
+                (
+                    "transition",
+                    format!("all {}s {}", self.duration, self.timing_fn),
+                ),
+            ],
+            hover: vec![
+                ("background-color", self.hover_bg_color.clone()),
+                ("width", self.hover_width.clone()),
+                ("height", self.hover_height.clone()),
+                ("margin", self.hover_margin.clone()),
+                ("border-radius", self.hover_radius.clone()),
+                ("opacity", self.hover_opacity.clone()),
+            ],
+            dark: vec![("background-color", self.dark_bg_color.clone())],
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct LineCssProps {
     size: String,
+    safe: SafeType,
     radius: String,
     margin: String,
 
@@ -59,351 +96,379 @@ struct LineCssProps {
     d_bg_color: String,
 }
 
+fn get_line_css(box_css_p: &LineCssProps) -> LineCss {
+    let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
+    let temp_h_size = if box_css_p.h_size == String::default() {
+        temp_size.clone()
+    } else {
+        box_css_p.h_size.split(" ").collect::<Vec<&str>>()
+    };
+
+    let temp_width = temp_size[0];
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+
+    let temp_h_width = temp_h_size[0];
+    let temp_h_height = if temp_h_size.len() == 1 {
+        temp_h_size[0]
+    } else {
+        temp_h_size[1]
+    };
+
+    let temp_width_op = add_op_space(temp_width);
+    let tmep_width_f = if temp_width.contains("%") {
+        temp_width.to_string()
+    } else {
+        format!("{}{}", temp_width, "px")
+    };
+    let temp_height_op = add_op_space(temp_height);
+    let temp_height_f = if temp_height.contains("%") {
+        temp_height.to_string()
+    } else {
+        format!("{}{}", temp_height, "px")
+    };
+
+    let temp_h_width_op = add_op_space(temp_h_width);
+    let tmep_h_width_f = if temp_h_width.contains("%") {
+        temp_h_width.to_string()
+    } else {
+        format!("{}{}", temp_h_width, "px")
+    };
+    let temp_h_height_op = add_op_space(temp_h_height);
+    let temp_h_height_f = if temp_h_height.contains("%") {
+        temp_h_height.to_string()
+    } else {
+        format!("{}{}", temp_h_height, "px")
+    };
+
+    let temp_radius = box_css_p.radius.as_str();
+    let temp_margin = box_css_p.margin.as_str();
+
+    let temp_radius_c = temp_radius
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || x.contains("/") {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let radius_value = if is_have_unit(temp_radius) {
+        temp_radius.to_owned()
+    } else {
+        temp_radius_c
+    };
+
+    let temp_margin_c = temp_margin
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let margin_value = if is_have_unit(temp_margin) {
+        temp_margin.to_owned()
+    } else {
+        temp_margin_c
+    };
+
+    let safe_sides = box_css_p.safe.get_sides();
+
+    LineCss {
+        width: if temp_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_width) {
+            temp_width_op
+        } else {
+            tmep_width_f
+        },
+        height: if temp_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_height) {
+            temp_height_op
+        } else {
+            temp_height_f
+        },
+        opacity: box_css_p.opacity.clone(),
+        margin: margin_value.clone(),
+        padding: safe_area_value("0", safe_sides, "env"),
+        padding_constant: safe_area_value("0", safe_sides, "constant"),
+        border_radius: radius_value.clone(),
+        background_color: box_css_p.bg_color.clone(),
+        background_image: if box_css_p.bg_image == "0" {
+            "none".to_owned()
+        } else {
+            if box_css_p.bg_image.contains("linear-gradient") {
+                box_css_p.bg_image.clone()
+            } else {
+                "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
+            }
+        },
+        cursor: box_css_p.cursor.get_name(),
+        flex_shrink: box_css_p.flex_shrink.clone(),
+
+        duration: box_css_p.duration.clone(),
+        timing_fn: box_css_p.timing_fn.get_name(),
+        hover_opacity: if box_css_p.h_opacity == String::default() {
+            box_css_p.opacity.clone()
+        } else {
+            box_css_p.h_opacity.clone()
+        },
+        hover_margin: if box_css_p.h_margin == String::default() {
+            margin_value
+        } else {
+            let temp = box_css_p.h_margin.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_radius: if box_css_p.h_radius == String::default() {
+            radius_value
+        } else {
+            let temp = box_css_p.h_radius.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || x.contains("/") {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_bg_color: if box_css_p.h_bg_color == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_bg_color.clone()
+        },
+        hover_width: if temp_h_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_width) {
+            temp_h_width_op
+        } else {
+            tmep_h_width_f
+        },
+        hover_height: if temp_h_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_height) {
+            temp_h_height_op
+        } else {
+            temp_h_height_f
+        },
+        dark_bg_color: if box_css_p.d_bg_color == String::default() {
+            box_css_p.bg_color.clone()
+        } else {
+            box_css_p.d_bg_color.clone()
+        },
+    }
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct LineProps {
-    #[prop_or(String::from("auto auto"))]
-    pub size: String,
-    #[prop_or(SafeType::None)]
-    pub safe: SafeType,
-    #[prop_or(String::from("0"))]
-    pub radius: String,
-    #[prop_or(String::from("0"))]
-    pub margin: String,
-
-    #[prop_or(String::from("transparent"))]
-    pub bg_color: String,
-    #[prop_or(String::from("0"))]
-    pub bg_image: String,
-
-    #[prop_or(Cursor::Unset)]
-    pub cursor: Cursor,
-    #[prop_or(String::from("1"))]
-    pub flex_shrink: String,
-
-    #[prop_or(String::from("inherit"))]
-    pub opacity: String,
-
-    #[prop_or(String::from("0"))]
-    pub duration: String,
-    #[prop_or(TimingFn::Ease)]
-    pub timing_fn: TimingFn,
-    #[prop_or(String::from(""))]
-    pub h_opacity: String,
-    #[prop_or(String::from(""))]
-    pub h_margin: String,
-    #[prop_or(String::from(""))]
-    pub h_radius: String,
-    #[prop_or(String::from(""))]
-    pub h_bg_color: String,
-    #[prop_or(String::from(""))]
-    pub h_size: String,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub safe: Option<SafeType>,
+    #[prop_or_default]
+    pub radius: Radius,
+    #[prop_or_default]
+    pub margin: Spacing,
+
+    #[prop_or_default]
+    pub bg_color: Option<String>,
+    #[prop_or_default]
+    pub bg_image: Option<String>,
+
+    #[prop_or_default]
+    pub cursor: Option<Cursor>,
+    #[prop_or_default]
+    pub flex_shrink: Option<String>,
+
+    #[prop_or_default]
+    pub opacity: Opacity,
+
+    #[prop_or_default]
+    pub duration: Duration,
+    #[prop_or_default]
+    pub timing_fn: Option<TimingFn>,
+    #[prop_or_default]
+    pub h_opacity: Opacity,
+    #[prop_or_default]
+    pub h_margin: Spacing,
+    #[prop_or_default]
+    pub h_radius: Radius,
+    #[prop_or_default]
+    pub h_bg_color: Option<String>,
+    #[prop_or_default]
+    pub h_size: Size,
+
+    #[prop_or_default]
+    pub d_bg_color: Option<String>,
 
     #[prop_or(String::from(""))]
-    pub d_bg_color: String,
+    pub testid: String,
 }
 
-/// ### 使用示例
-///```
-/// size: String,
-/// radius: String,
-/// margin: String, // "1 2 2 1"
-/// bg_color: String,
-/// bg_image: String,
-/// cursor: Cursor,
-/// flex_shrink: String,
-/// opacity: String,
-/// duration: String,
-/// timing_fn: TimingFn, // transition 的动画方式
-/// h_opacity: String,  //hover 样式 "0.7"
-/// h_margin: String,  //hover 样式 "0 0 12 12"
-/// h_radius: String,    //hover 样式 "12"
-/// h_bg_color: String,
-/// h_size: String,
-/// d_bg_color: String,  // dark 模式
-///```
-///
-#[function_component]
-pub fn Line(props: &LineProps) -> Html {
-    let box_css_p: LineCssProps = LineCssProps {
-        size: props.size.clone(),
+resolved_props!(
+    ResolvedLineProps for LineProps {
+        size: Size = Size::from("auto auto"),
+        safe: SafeType = SafeType::None,
+        radius: Radius = Radius::from("0"),
+        margin: Spacing = Spacing::from("0"),
+        bg_color: String = String::from("transparent"),
+        bg_image: String = String::from("0"),
+        cursor: Cursor = Cursor::Unset,
+        flex_shrink: String = String::from("1"),
+        opacity: Opacity = Opacity::from("inherit"),
+        duration: Duration = Duration::from("0"),
+        timing_fn: TimingFn = TimingFn::Ease,
+        h_opacity: Opacity = Opacity::from(""),
+        h_margin: Spacing = Spacing::from(""),
+        h_radius: Radius = Radius::from(""),
+        h_bg_color: String = String::from(""),
+        h_size: Size = Size::from(""),
+        d_bg_color: String = String::from(""),
+    }
+    testid: String,
+);
+
+/// 组件生成的 css，可在原生环境中调用（如测试）
+/// 断点、设计稿换算使用默认值，不含 ConfigProvider
+pub fn line_css(props: &LineProps) -> String {
+    build_line_css(
+        &get_line_css_props(&resolve_line_props(props, None)),
+        &Breakpoints::default(),
+        &Design::default(),
+    )
+}
 
-        radius: props.radius.clone(),
-        margin: props.margin.clone(),
+/// 依次使用内置默认值、ConfigProvider、组件中设置的属性
+fn resolve_line_props(props: &LineProps, config: Option<&LineProps>) -> ResolvedLineProps {
+    let mut p = ResolvedLineProps::new(props, config);
+    p.apply(props);
+    p
+}
+
+fn get_line_css_props(props: &ResolvedLineProps) -> LineCssProps {
+    LineCssProps {
+        size: props.size.to_string(),
+        safe: props.safe.clone(),
+
+        radius: props.radius.to_string(),
+        margin: props.margin.to_string(),
 
         bg_color: props.bg_color.clone(),
         bg_image: props.bg_image.clone(),
 
         cursor: props.cursor.clone(),
-        opacity: props.opacity.clone(),
+        opacity: props.opacity.to_string(),
         flex_shrink: props.flex_shrink.clone(),
 
-        duration: props.duration.clone(),
+        duration: props.duration.to_string(),
         timing_fn: props.timing_fn.clone(),
-        h_opacity: props.h_opacity.clone(),
-        h_margin: props.h_margin.clone(),
-        h_radius: props.h_radius.clone(),
+        h_opacity: props.h_opacity.to_string(),
+        h_margin: props.h_margin.to_string(),
+        h_radius: props.h_radius.to_string(),
         h_bg_color: props.h_bg_color.clone(),
-        h_size: props.h_size.clone(),
+        h_size: props.h_size.to_string(),
 
         d_bg_color: props.d_bg_color.clone(),
-    };
-
-    let box_css = use_memo(
-        |box_css_p| {
-            let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
-            let temp_h_size = if box_css_p.h_size == String::default() {
-                temp_size.clone()
-            } else {
-                box_css_p.h_size.split(" ").collect::<Vec<&str>>()
-            };
-
-            let temp_width = temp_size[0];
-            let temp_height = if temp_size.len() == 1 {
-                temp_size[0]
-            } else {
-                temp_size[1]
-            };
-
-            let temp_h_width = temp_h_size[0];
-            let temp_h_height = if temp_h_size.len() == 1 {
-                temp_h_size[0]
-            } else {
-                temp_h_size[1]
-            };
-
-            let temp_width_op = add_op_space(temp_width);
-            let tmep_width_f = if temp_width.contains("%") {
-                format!("{}", temp_width)
-            } else {
-                format!("{}{}", temp_width, "px")
-            };
-            let temp_height_op = add_op_space(temp_height);
-            let temp_height_f = if temp_height.contains("%") {
-                format!("{}", temp_height)
-            } else {
-                format!("{}{}", temp_height, "px")
-            };
+    }
+}
 
-            let temp_h_width_op = add_op_space(temp_h_width);
-            let tmep_h_width_f = if temp_h_width.contains("%") {
-                format!("{}", temp_h_width)
-            } else {
-                format!("{}{}", temp_h_width, "px")
-            };
-            let temp_h_height_op = add_op_space(temp_h_height);
-            let temp_h_height_f = if temp_h_height.contains("%") {
-                format!("{}", temp_h_height)
-            } else {
-                format!("{}{}", temp_h_height, "px")
-            };
-
-            let temp_radius = box_css_p.radius.as_str();
-            let temp_margin = box_css_p.margin.as_str();
-
-            let temp_radius_c = temp_radius
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") || x.contains("/") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let radius_value = if is_have_unit(temp_radius) {
-                temp_radius.to_owned()
-            } else {
-                temp_radius_c
-            };
-
-            let temp_margin_c = temp_margin
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let margin_value = if is_have_unit(temp_margin) {
-                temp_margin.to_owned()
-            } else {
-                temp_margin_c
-            };
-
-            let p_top = match props.safe {
-                SafeType::None => "0".to_owned(),
-                SafeType::Top => "env(safe-area-inset-top)".to_owned(),
-                SafeType::Bottom => "0".to_owned(),
-            };
-            let p_bottom = match props.safe {
-                SafeType::None => "0".to_owned(),
-                SafeType::Top => "0".to_owned(),
-                SafeType::Bottom => "env(safe-area-inset-bottom)".to_owned(),
-            };
-
-            LineCss {
-                width: if temp_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_width) {
-                    temp_width_op
-                } else {
-                    tmep_width_f
-                },
-                height: if temp_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_height) {
-                    temp_height_op
-                } else {
-                    temp_height_f
-                },
-                opacity: box_css_p.opacity.clone(),
-                margin: margin_value.clone(),
-                padding_top: p_top,
-                padding_bottom: p_bottom,
-                border_radius: radius_value.clone(),
-                background_color: box_css_p.bg_color.clone(),
-                background_image: if box_css_p.bg_image == "0" {
-                    "none".to_owned()
-                } else {
-                    if box_css_p.bg_image.contains("linear-gradient") {
-                        box_css_p.bg_image.clone()
-                    } else {
-                        "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
-                    }
-                },
-                cursor: box_css_p.cursor.get_name(),
-                flex_shrink: box_css_p.flex_shrink.clone(),
-
-                duration: box_css_p.duration.clone(),
-                timing_fn: box_css_p.timing_fn.get_name(),
-                hover_opacity: if box_css_p.h_opacity == String::default() {
-                    box_css_p.opacity.clone()
-                } else {
-                    box_css_p.h_opacity.clone()
-                },
-                hover_margin: if box_css_p.h_margin == String::default() {
-                    margin_value
-                } else {
-                    let temp = box_css_p.h_margin.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_radius: if box_css_p.h_radius == String::default() {
-                    radius_value
-                } else {
-                    let temp = box_css_p.h_radius.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") || x.contains("/") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_bg_color: if box_css_p.h_bg_color == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_bg_color.clone()
-                },
-                hover_width: if temp_h_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_width) {
-                    temp_h_width_op
-                } else {
-                    tmep_h_width_f
-                },
-                hover_height: if temp_h_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_height) {
-                    temp_h_height_op
-                } else {
-                    temp_h_height_f
-                },
-                dark_bg_color: if box_css_p.d_bg_color == String::default() {
-                    box_css_p.bg_color.clone()
-                } else {
-                    box_css_p.d_bg_color.clone()
-                },
-            }
-        },
+fn build_line_css(box_css_p: &LineCssProps, breakpoints: &Breakpoints, design: &Design) -> String {
+    let box_css_p =
+        &design_props!(box_css_p, design, size, radius, margin, h_margin, h_radius, h_size);
+    let (base, points) = responsive_props!(
         box_css_p,
+        breakpoints,
+        size,
+        radius,
+        margin,
+        bg_color,
+        bg_image,
+        opacity,
+        duration,
+        h_opacity,
+        h_margin,
+        h_radius,
+        h_bg_color,
+        h_size,
+        flex_shrink,
+        d_bg_color,
     );
+    let points = points
+        .iter()
+        .map(|(name, p)| (name.clone(), get_line_css(p).to_blocks()))
+        .collect();
+    responsive_css(&get_line_css(&base).to_blocks(), points, breakpoints)
+}
 
-    let class = css!(
-        r#"
-            width: ${width};
-            height: ${height};
-            padding-top: ${padding_top};
-            padding-bottom: ${padding_bottom};
-            margin: ${margin};
-            border-radius: ${radius};
-            background-color: ${bg_color};
-            background-image: ${bg_img};
-            cursor: ${cursor};
-            flex-shrink: ${flex_shrink};
-
-            transition: all ${duration}s ${timing_fn};
-
-            &:hover {
-                background-color: ${hover_bg_color};
-                width: ${hover_width};
-                height: ${hover_height};
-                margin: ${hover_margin};
-                border-radius: ${hover_radius};
-                opacity: ${hover_opacity};
-            }
-
-            @media (prefers-color-scheme: dark) {
-              & {
-                background-color: ${dark_bg_color};
-              }
-            }
-        "#,
-        padding_bottom = box_css.padding_bottom,
-        padding_top = box_css.padding_top,
-        margin = box_css.margin,
-        width = box_css.width,
-        height = box_css.height,
-        radius = box_css.border_radius,
-        bg_color = box_css.background_color,
-        bg_img = box_css.background_image,
-        cursor = box_css.cursor,
-        flex_shrink = box_css.flex_shrink,
-        duration = box_css.duration,
-        timing_fn = box_css.timing_fn,
-        hover_opacity = box_css.hover_opacity,
-        hover_margin = box_css.hover_margin,
-        hover_radius = box_css.hover_radius,
-        hover_bg_color = box_css.hover_bg_color,
-        hover_width = box_css.hover_width,
-        hover_height = box_css.hover_height,
-        dark_bg_color = box_css.dark_bg_color,
+/// ### 使用示例
+///```text
+/// size: Size,
+/// safe: SafeType, // 安全区域的高度（宽度）
+/// radius: Radius,
+/// margin: Spacing, // "1 2 2 1"
+/// bg_color: String,
+/// bg_image: String,
+/// cursor: Cursor,
+/// flex_shrink: String,
+/// opacity: Opacity,
+/// duration: Duration,
+/// timing_fn: TimingFn, // transition 的动画方式
+/// h_opacity: Opacity,  //hover 样式 "0.7"
+/// h_margin: Spacing,  //hover 样式 "0 0 12 12"
+/// h_radius: Radius,    //hover 样式 "12"
+/// h_bg_color: String,
+/// h_size: Size,
+/// d_bg_color: String,  // dark 模式
+/// testid: String, // 渲染为 data-testid
+///```
+///
+#[function_component]
+pub fn Line(props: &LineProps) -> Html {
+    let config = use_config();
+    let props = &resolve_line_props(props, config.line_props.as_deref());
+    let box_css_p = get_line_css_props(props);
+
+    let breakpoints = use_breakpoints();
+    let design = use_design();
+    let style = use_memo(
+        |(box_css_p, breakpoints, design)| {
+            style_source(build_line_css(box_css_p, breakpoints, design))
+        },
+        (box_css_p, breakpoints, design),
     );
+    let class = (*style).clone();
 
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
     html! {
-        <div {class} />
+        <div {class} data-testid={testid} />
     }
 }
@@ -1,6 +1,21 @@
 mod types;
 pub use types::*;
 
+mod value;
+pub use value::*;
+
+mod breakpoint;
+pub use breakpoint::*;
+
+mod design;
+pub use design::*;
+
+mod theme;
+pub use theme::*;
+
+mod config;
+pub use config::*;
+
 mod r#box;
 pub use r#box::*;
 
@@ -13,11 +28,41 @@ pub use line::*;
 mod image;
 pub use image::*;
 
+mod image_preview;
+pub use image_preview::*;
+
+mod image_cropper;
+pub use image_cropper::*;
+
 mod text;
 pub use text::*;
 
 mod text_ellipsis;
 pub use text_ellipsis::*;
 
+mod truncate;
+pub use truncate::*;
+
+mod highlight;
+pub use highlight::*;
+
 mod block;
 pub use block::*;
+
+mod pull_refresh;
+pub use pull_refresh::*;
+
+mod virtual_list;
+pub use virtual_list::*;
+
+mod safe_area;
+pub use safe_area::*;
+
+mod affix;
+pub use affix::*;
+
+mod portal;
+pub use portal::*;
+
+mod show;
+pub use show::*;
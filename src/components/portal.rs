@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+
+use yew::{
+    create_portal, function_component, hook, html, use_effect_with_deps, use_state, Children, Html,
+    Properties,
+};
+
+use crate::prelude::{StyleProp, ZIndex};
+use crate::utils::style_source;
+
+thread_local! {
+    /// 当前打开的浮层，保存各自的 z-index
+    static LAYERS: RefCell<Vec<i32>> = const { RefCell::new(vec![]) };
+}
+
+/// 浮层 z-index 的起始值
+pub const LAYER_BASE_Z_INDEX: i32 = 1000;
+
+/// 获取浮层的 z-index，后打开（嵌套）的浮层总在先打开的浮层之上，卸载时释放
+/// SSR 时 effect 的清理不会执行，不登记，都为 LAYER_BASE_Z_INDEX
+#[hook]
+pub fn use_z_index() -> i32 {
+    let z_index = use_state(|| {
+        if !cfg!(target_arch = "wasm32") {
+            return LAYER_BASE_Z_INDEX;
+        }
+        LAYERS.with(|layers| {
+            let mut layers = layers.borrow_mut();
+            let z = layers.last().map(|x| x + 1).unwrap_or(LAYER_BASE_Z_INDEX);
+            layers.push(z);
+            z
+        })
+    });
+    {
+        let z = *z_index;
+        use_effect_with_deps(
+            move |_| {
+                move || {
+                    LAYERS.with(|layers| layers.borrow_mut().retain(|x| *x != z));
+                }
+            },
+            (),
+        );
+    }
+    *z_index
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PortalProps {
+    #[prop_or(String::from(""))]
+    pub host_id: String,
+    #[prop_or_default]
+    pub z_index: ZIndex,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// host_id: String, // 渲染到的元素 id，默认为 document.body
+/// z_index: ZIndex, // 默认由浮层栈自动分配
+///```
+#[function_component]
+pub fn Portal(props: &PortalProps) -> Html {
+    let host = use_state(|| None::<web_sys::Element>);
+    let layer_z_index = use_z_index();
+
+    {
+        let host = host.clone();
+        use_effect_with_deps(
+            move |host_id| {
+                let document = web_sys::window().and_then(|w| w.document());
+                let el = document.and_then(|d| {
+                    if host_id == &String::default() {
+                        d.body().map(|b| b.into())
+                    } else {
+                        d.get_element_by_id(host_id)
+                    }
+                });
+                host.set(el);
+                || ()
+            },
+            props.host_id.clone(),
+        );
+    }
+
+    let class = style_source(format!(
+        r#"
+            position: absolute;
+            top: 0;
+            left: 0;
+            width: 100%;
+            z-index: {z_index};
+        "#,
+        z_index = match props.z_index.get() {
+            Some(z_index) => z_index.to_string(),
+            None => layer_z_index.to_string(),
+        },
+    ));
+
+    match &*host {
+        Some(host) => create_portal(
+            html! {
+                <div {class}>
+                { for props.children.iter() }
+                </div>
+            },
+            host.clone(),
+        ),
+        None => html! {},
+    }
+}
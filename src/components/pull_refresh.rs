@@ -0,0 +1,280 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use yew::platform::{spawn_local, time::sleep};
+use yew::{
+    function_component, html, use_mut_ref, use_node_ref, use_state, Callback, Children, Html,
+    Properties, TouchEvent,
+};
+
+use crate::prelude::{Duration, PullStatus, Size, TimingFn};
+use crate::utils::{is_have_unit, style_source};
+
+/// on_refresh 返回的异步任务，任务结束后，刷新状态结束
+pub type RefreshFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PullRefreshProps {
+    pub on_refresh: Callback<(), RefreshFuture>,
+    #[prop_or(false)]
+    pub disabled: bool,
+    #[prop_or(Size::from("100% auto"))]
+    pub size: Size,
+    #[prop_or(String::from("50"))]
+    pub head_height: String,
+    #[prop_or(String::from(""))]
+    pub pull_distance: String,
+    #[prop_or(Duration::from("0.5"))]
+    pub success_duration: Duration,
+
+    #[prop_or(String::from(""))]
+    pub pulling_text: String,
+    #[prop_or(String::from(""))]
+    pub loosing_text: String,
+    #[prop_or(String::from(""))]
+    pub loading_text: String,
+    #[prop_or(String::from(""))]
+    pub success_text: String,
+    #[prop_or(String::from("14"))]
+    pub font_size: String,
+    #[prop_or(String::from("#969799"))]
+    pub color: String,
+    #[prop_or_default]
+    pub indicator: Option<Callback<(PullStatus, f64), Html>>,
+
+    #[prop_or(Duration::from("0.3"))]
+    pub duration: Duration,
+    #[prop_or(TimingFn::Ease)]
+    pub timing_fn: TimingFn,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// on_refresh: Callback<(), RefreshFuture>, // 下拉刷新的异步任务 Callback::from(|_| Box::pin(async {}) as RefreshFuture)
+/// disabled: bool,  // 禁用下拉刷新
+/// size: Size,  // 容器长宽，高度固定时，内部滚动
+/// head_height: String, // 顶部提示区域的高度 "50"
+/// pull_distance: String, // 触发刷新的下拉距离，默认同 head_height
+/// success_duration: Duration, // 刷新成功提示的展示时间 "0.5" "500ms"
+/// pulling_text: String, // 下拉过程中的提示文字
+/// loosing_text: String, // 释放过程中的提示文字
+/// loading_text: String, // 加载过程中的提示文字
+/// success_text: String, // 刷新成功的提示文字
+/// font_size: String,
+/// color: String,
+/// indicator: Option<Callback<(PullStatus, f64), Html>>, // 自定义提示区域，参数为状态和下拉距离
+/// duration: Duration, // 回弹动画时间 "0.3" "300ms"
+/// timing_fn: TimingFn, // 回弹动画的动画方式
+///```
+#[function_component]
+pub fn PullRefresh(props: &PullRefreshProps) -> Html {
+    let node = use_node_ref();
+    let status = use_state(|| PullStatus::Normal);
+    let distance = use_state(|| 0.0_f64);
+    let dragging = use_state(|| false);
+    let start_y = use_mut_ref(|| 0.0_f64);
+
+    let head_height = props.head_height.parse::<f64>().unwrap_or(50.0);
+    let pull_distance = props.pull_distance.parse::<f64>().unwrap_or(head_height);
+
+    let is_touchable =
+        !props.disabled && *status != PullStatus::Loading && *status != PullStatus::Success;
+
+    let ontouchstart = {
+        let start_y = start_y.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |e: TouchEvent| {
+            if !is_touchable {
+                return;
+            }
+            if let Some(touch) = e.touches().get(0) {
+                *start_y.borrow_mut() = touch.client_y() as f64;
+                dragging.set(true);
+            }
+        })
+    };
+
+    let ontouchmove = {
+        let node = node.clone();
+        let status = status.clone();
+        let distance = distance.clone();
+        let start_y = start_y.clone();
+        Callback::from(move |e: TouchEvent| {
+            if !is_touchable {
+                return;
+            }
+            let scroll_top = node
+                .cast::<web_sys::Element>()
+                .map(|el| el.scroll_top())
+                .unwrap_or(0)
+                + web_sys::window()
+                    .and_then(|w| w.scroll_y().ok())
+                    .unwrap_or(0.0) as i32;
+            if scroll_top > 0 {
+                return;
+            }
+            if let Some(touch) = e.touches().get(0) {
+                let delta = touch.client_y() as f64 - *start_y.borrow();
+                if delta <= 0.0 {
+                    distance.set(0.0);
+                    status.set(PullStatus::Normal);
+                    return;
+                }
+                let d = ease_distance(delta, pull_distance);
+                distance.set(d);
+                status.set(if d >= pull_distance {
+                    PullStatus::Loosing
+                } else {
+                    PullStatus::Pulling
+                });
+            }
+        })
+    };
+
+    let ontouchend = {
+        let status = status.clone();
+        let distance = distance.clone();
+        let dragging = dragging.clone();
+        let on_refresh = props.on_refresh.clone();
+        let success_duration = props
+            .success_duration
+            .to_string()
+            .parse::<f64>()
+            .unwrap_or(0.5);
+        Callback::from(move |_: TouchEvent| {
+            if !is_touchable {
+                return;
+            }
+            dragging.set(false);
+            if *status == PullStatus::Loosing {
+                status.set(PullStatus::Loading);
+                distance.set(head_height);
+                let task = on_refresh.emit(());
+                let status = status.clone();
+                let distance = distance.clone();
+                spawn_local(async move {
+                    task.await;
+                    status.set(PullStatus::Success);
+                    sleep(std::time::Duration::from_secs_f64(
+                        success_duration.max(0.0),
+                    ))
+                    .await;
+                    status.set(PullStatus::Normal);
+                    distance.set(0.0);
+                });
+            } else {
+                status.set(PullStatus::Normal);
+                distance.set(0.0);
+            }
+        })
+    };
+
+    let size = props.size.to_string();
+    let temp_size = size.split(" ").collect::<Vec<&str>>();
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+    let size_value = |v: &str| {
+        if v == "auto" || is_have_unit(v) || v.contains("%") {
+            v.to_string()
+        } else {
+            v.to_string() + "px"
+        }
+    };
+
+    let class = style_source(format!(
+        r#"
+            position: relative;
+            width: {width};
+            height: {height};
+            overflow-x: hidden;
+            overflow-y: {overflow_y};
+            overscroll-behavior-y: contain;
+
+            .pull-refresh-track {{
+                position: relative;
+                transition-property: transform;
+                transition-timing-function: {timing_fn};
+            }}
+            .pull-refresh-head {{
+                position: absolute;
+                left: 0;
+                width: 100%;
+                height: {head_height}px;
+                transform: translateY(-100%);
+                display: flex;
+                justify-content: center;
+                align-items: center;
+                overflow: hidden;
+                font-size: {font_size};
+                color: {color};
+            }}
+        "#,
+        width = size_value(temp_size[0]),
+        height = size_value(temp_height),
+        overflow_y = if temp_height == "auto" {
+            "hidden"
+        } else {
+            "auto"
+        },
+        timing_fn = props.timing_fn.get_name(),
+        head_height = head_height,
+        font_size = size_value(props.font_size.as_str()),
+        color = props.color.clone(),
+    ));
+
+    let text = match *status {
+        PullStatus::Normal => String::default(),
+        PullStatus::Pulling => props.pulling_text.clone(),
+        PullStatus::Loosing => props.loosing_text.clone(),
+        PullStatus::Loading => props.loading_text.clone(),
+        PullStatus::Success => props.success_text.clone(),
+    };
+    let text = if text == String::default() {
+        status.get_name()
+    } else {
+        text
+    };
+    let head = match &props.indicator {
+        Some(indicator) => indicator.emit(((*status).clone(), *distance)),
+        None => html! { {text} },
+    };
+
+    let track_style = format!(
+        "transform: translate3d(0, {}px, 0); transition-duration: {}s;",
+        *distance,
+        if *dragging {
+            String::from("0")
+        } else {
+            props.duration.to_string()
+        }
+    );
+
+    html! {
+        <div {class} ref={node} {ontouchstart} {ontouchmove} ontouchend={ontouchend.clone()} ontouchcancel={ontouchend}>
+            <div class="pull-refresh-track" style={track_style}>
+                <div class="pull-refresh-head">{ head }</div>
+                { for props.children.iter() }
+            </div>
+        </div>
+    }
+}
+
+/// 下拉距离的阻尼，超过触发距离后，越拉越难
+fn ease_distance(delta: f64, pull_distance: f64) -> f64 {
+    let d = delta / 2.0;
+    if d > pull_distance {
+        if d < pull_distance * 2.0 {
+            pull_distance + (d - pull_distance) / 2.0
+        } else {
+            pull_distance * 1.5 + (d - pull_distance * 2.0) / 4.0
+        }
+    } else {
+        d
+    }
+}
@@ -0,0 +1,136 @@
+use yew::{function_component, html, use_memo, Children, Html, Properties};
+
+use crate::prelude::{use_design, SafeMode, SafeType, Spacing};
+use crate::utils::{design_props, is_have_unit, safe_area_value, style_source};
+
+#[derive(Clone, PartialEq)]
+struct SafeAreaCss {
+    padding: String,
+    padding_constant: String,
+    margin: String,
+    margin_constant: String,
+    background_color: String,
+    dark_bg_color: String,
+}
+
+#[derive(Clone, PartialEq)]
+struct SafeAreaCssProps {
+    safe: SafeType,
+    mode: SafeMode,
+    padding: String,
+    margin: String,
+    bg_color: String,
+    d_bg_color: String,
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct SafeAreaProps {
+    #[prop_or(SafeType::All)]
+    pub safe: SafeType,
+    #[prop_or(SafeMode::Padding)]
+    pub mode: SafeMode,
+    #[prop_or(Spacing::from("0"))]
+    pub padding: Spacing,
+    #[prop_or(Spacing::from("0"))]
+    pub margin: Spacing,
+    #[prop_or(String::from("transparent"))]
+    pub bg_color: String,
+    #[prop_or(String::from(""))]
+    pub d_bg_color: String,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// ### 使用示例
+///```text
+/// safe: SafeType, // 需要避开的安全区域，默认四边
+/// mode: SafeMode, // 安全区域作为 padding 或 margin
+/// padding: Spacing, // "1 2 2 1"，安全区域会加在原有的 padding 上
+/// margin: Spacing, // "1 2 2 1"，安全区域会加在原有的 margin 上
+/// bg_color: String,
+/// d_bg_color: String, // dark 模式
+///```
+#[function_component]
+pub fn SafeArea(props: &SafeAreaProps) -> Html {
+    let box_css_p = SafeAreaCssProps {
+        safe: props.safe.clone(),
+        mode: props.mode.clone(),
+        padding: props.padding.to_string(),
+        margin: props.margin.to_string(),
+        bg_color: props.bg_color.clone(),
+        d_bg_color: props.d_bg_color.clone(),
+    };
+
+    let design = use_design();
+    let box_css = use_memo(
+        |(box_css_p, design)| {
+            let box_css_p = &design_props!(box_css_p, design, padding, margin);
+            let to_value = |data: &str| {
+                if is_have_unit(data) {
+                    data.to_owned()
+                } else {
+                    data.split(" ")
+                        .map(|x| {
+                            if x.contains("%") || is_have_unit(x) {
+                                x.to_string()
+                            } else {
+                                x.to_string() + "px"
+                            }
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                }
+            };
+            let padding_value = to_value(&box_css_p.padding);
+            let margin_value = to_value(&box_css_p.margin);
+            let (padding_sides, margin_sides) = match box_css_p.mode {
+                SafeMode::Padding => (box_css_p.safe.get_sides(), SafeType::None.get_sides()),
+                SafeMode::Margin => (SafeType::None.get_sides(), box_css_p.safe.get_sides()),
+            };
+            SafeAreaCss {
+                padding: safe_area_value(&padding_value, padding_sides, "env"),
+                padding_constant: safe_area_value(&padding_value, padding_sides, "constant"),
+                margin: safe_area_value(&margin_value, margin_sides, "env"),
+                margin_constant: safe_area_value(&margin_value, margin_sides, "constant"),
+                background_color: box_css_p.bg_color.clone(),
+                dark_bg_color: if box_css_p.d_bg_color == String::default() {
+                    box_css_p.bg_color.clone()
+                } else {
+                    box_css_p.d_bg_color.clone()
+                },
+            }
+        },
+        (box_css_p, design),
+    );
+
+    let class = style_source(format!(
+        r#"
+            display: block;
+            box-sizing: border-box;
+            padding: {padding_constant};
+            padding: {padding};
+            margin: {margin_constant};
+            margin: {margin};
+            background-color: {bg_color};
+
+            @media (prefers-color-scheme: dark) {{
+              & {{
+                background-color: {dark_bg_color};
+              }}
+            }}
+        "#,
+        padding = box_css.padding,
+        padding_constant = box_css.padding_constant,
+        margin = box_css.margin,
+        margin_constant = box_css.margin_constant,
+        bg_color = box_css.background_color,
+        dark_bg_color = box_css.dark_bg_color,
+    ));
+
+    html! {
+        <div {class}>
+        { for props.children.iter() }
+        </div>
+    }
+}
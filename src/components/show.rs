@@ -0,0 +1,153 @@
+use yew::{function_component, hook, html, Children, Html, Properties};
+
+use crate::prelude::{use_breakpoints, use_media_query, Breakpoints, ShowMode};
+use crate::utils::style_source;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ShowProps {
+    #[prop_or(String::from(""))]
+    pub above: String,
+    #[prop_or(String::from(""))]
+    pub below: String,
+    #[prop_or(String::from(""))]
+    pub query: String,
+    #[prop_or(ShowMode::Css)]
+    pub mode: ShowMode,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// 比断点小 0.02px 的宽度，与其他条件组合时用于 max-width
+/// "768px" => "767.98px"
+fn below_width(width: &str) -> String {
+    let unit_at = width
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(width.len());
+    match width[..unit_at].parse::<f64>() {
+        Ok(value) => format!("{}{}", value - 0.02, &width[unit_at..]),
+        Err(_) => width.to_owned(),
+    }
+}
+
+/// 各条件同时满足时的媒体查询，没有条件时为 "all"
+/// 只有 below 时为 "not all and (min-width: X)"，not 作用于整个查询，与其他条件组合时改用 max-width
+fn get_condition(props: &ShowProps, breakpoints: &Breakpoints) -> String {
+    let mut list = vec![];
+    if props.query != String::default() {
+        list.push(props.query.clone());
+    }
+    if let Some(width) = breakpoints.get_width(&props.above) {
+        list.push(format!("(min-width: {})", width));
+    }
+    if let Some(width) = breakpoints.get_width(&props.below) {
+        if list.is_empty() {
+            return format!("not all and (min-width: {})", width);
+        }
+        list.push(format!("(max-width: {})", below_width(&width)));
+    }
+    if list.is_empty() {
+        String::from("all")
+    } else {
+        list.join(" and ")
+    }
+}
+
+/// 返回 (媒体查询, 是否匹配)，只有 Unmount 模式才监听 matchMedia
+#[hook]
+fn use_condition(props: &ShowProps) -> (String, bool) {
+    let breakpoints = use_breakpoints();
+    let condition = get_condition(props, &breakpoints);
+    let matches = use_media_query(match props.mode {
+        ShowMode::Unmount => &condition,
+        ShowMode::Css => "not all",
+    });
+    (condition, matches)
+}
+
+fn render(props: &ShowProps, condition: String, matches: bool, hide: bool) -> Html {
+    match props.mode {
+        ShowMode::Unmount => {
+            if matches != hide {
+                html! { <>{ for props.children.iter() }</> }
+            } else {
+                html! {}
+            }
+        }
+        ShowMode::Css => {
+            let (display, matched_display) = if hide {
+                ("contents", "none")
+            } else {
+                ("none", "contents")
+            };
+            let class = style_source(format!(
+                "display: {};\n@media {} {{\n& {{\ndisplay: {};\n}}\n}}\n",
+                display, condition, matched_display
+            ));
+            html! {
+                <div {class}>
+                { for props.children.iter() }
+                </div>
+            }
+        }
+    }
+}
+
+/// 满足条件时显示
+/// ### 使用示例
+///```text
+/// above: String, // 断点名称，视口宽度 >= 断点时满足，如 "md"
+/// below: String, // 断点名称，视口宽度 < 断点时满足
+/// query: String, // 媒体查询，如 "(prefers-reduced-motion: reduce)"，多个条件同时满足
+/// mode: ShowMode, // Css 通过 display 切换，Unmount 不满足时不渲染子组件
+///```
+#[function_component]
+pub fn Show(props: &ShowProps) -> Html {
+    let (condition, matches) = use_condition(props);
+    render(props, condition, matches, false)
+}
+
+/// 满足条件时隐藏，属性同 Show
+/// ### 使用示例
+///```text
+/// above: String,
+/// below: String,
+/// query: String,
+/// mode: ShowMode,
+///```
+#[function_component]
+pub fn Hide(props: &ShowProps) -> Html {
+    let (condition, matches) = use_condition(props);
+    render(props, condition, matches, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_condition;
+    use crate::prelude::{Breakpoints, ShowMode, ShowProps};
+
+    fn condition(above: &str, below: &str, query: &str) -> String {
+        let props = ShowProps {
+            above: above.to_owned(),
+            below: below.to_owned(),
+            query: query.to_owned(),
+            mode: ShowMode::Css,
+            children: Default::default(),
+        };
+        get_condition(&props, &Breakpoints::default())
+    }
+
+    #[test]
+    fn conditions() {
+        assert_eq!(condition("", "", ""), "all");
+        assert_eq!(condition("md", "", ""), "(min-width: 768px)");
+        assert_eq!(condition("", "md", ""), "not all and (min-width: 768px)");
+        assert_eq!(
+            condition("sm", "lg", ""),
+            "(min-width: 640px) and (max-width: 1023.98px)"
+        );
+        assert_eq!(
+            condition("", "md", "(hover: hover)"),
+            "(hover: hover) and (max-width: 767.98px)"
+        );
+    }
+}
@@ -2,12 +2,15 @@ use yew::{
     function_component, html, use_memo, Callback, Children, Html, MouseEvent, NodeRef, Properties,
 };
 
-use stylist::css;
-
 use crate::prelude::{
-    Cursor, Display, FontStyle, FontWeight, TextAlign, TimingFn, WhiteSpace, WordBreak,
+    use_breakpoints, use_config, use_design, Breakpoints, Cursor, Design, Display, Duration,
+    FontStyle, FontWeight, Opacity, Size, Spacing, TextAlign, Theme, TimingFn, WhiteSpace,
+    WordBreak, ZIndex,
+};
+use crate::utils::{
+    add_op_space, design_props, is_have_unit, resolved_props, responsive_css, responsive_props,
+    style_source, sx_props, CssBlocks, SxStyle,
 };
-use crate::utils::{add_op_space, is_have_unit};
 
 #[derive(Clone, PartialEq)]
 struct TextCss {
@@ -50,6 +53,51 @@ struct TextCss {
     dark_color: String,
 }
 
+impl TextCss {
+    fn to_blocks(&self) -> CssBlocks {
+        CssBlocks {
+            base: vec![
+                ("display", self.display.clone()),
+                ("width", self.width.clone()),
+                ("height", self.height.clone()),
+                ("padding", self.padding.clone()),
+                ("margin", self.margin.clone()),
+                ("background-image", self.background_image.clone()),
+                ("cursor", self.cursor.clone()),
+                ("white-space", self.white_space.clone()),
+                ("min-width", self.min_width.clone()),
+                ("min-height", self.min_height.clone()),
+                ("max-width", self.max_width.clone()),
+                ("max-height", self.max_height.clone()),
+                ("z-index", self.z_index.clone()),
+                ("opacity", self.opacity.clone()),
+                ("font-size", self.font_size.clone()),
+                ("color", self.color.clone()),
+                ("font-style", self.font_style.clone()),
+                ("font-weight", self.font_weight.clone()),
+                ("letter-spacing", self.letter_spacing.clone()),
+                ("line-height", self.line_height.clone()),
+                ("text-decoration", self.text_decoration.clone()),
+                ("text-align", self.text_align.clone()),
+                ("word-break", self.word_break.clone()),
+                (
+                    "transition",
+                    format!("all {}s {}", self.duration, self.timing_fn),
+                ),
+            ],
+            hover: vec![
+                ("color", self.hover_color.clone()),
+                ("width", self.hover_width.clone()),
+                ("height", self.hover_height.clone()),
+                ("padding", self.hover_padding.clone()),
+                ("margin", self.hover_margin.clone()),
+                ("opacity", self.hover_opacity.clone()),
+            ],
+            dark: vec![("color", self.dark_color.clone())],
+        }
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct TextCssProps {
     display: Display,
@@ -87,70 +135,356 @@ struct TextCssProps {
     d_color: String,
 }
 
+fn get_text_css(box_css_p: &TextCssProps) -> TextCss {
+    let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
+    let temp_h_size = if box_css_p.h_size == String::default() {
+        temp_size.clone()
+    } else {
+        box_css_p.h_size.split(" ").collect::<Vec<&str>>()
+    };
+    let temp_min_size = box_css_p.min_size.split(" ").collect::<Vec<&str>>();
+    let temp_max_size = box_css_p.max_size.split(" ").collect::<Vec<&str>>();
+
+    let temp_width = temp_size[0];
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+
+    let temp_h_width = temp_h_size[0];
+    let temp_h_height = if temp_h_size.len() == 1 {
+        temp_h_size[0]
+    } else {
+        temp_h_size[1]
+    };
+
+    let temp_min_width = temp_min_size[0];
+    let temp_min_height = if temp_min_size.len() == 1 {
+        temp_min_size[0]
+    } else {
+        temp_min_size[1]
+    };
+    let temp_max_width = temp_max_size[0];
+    let temp_max_height = if temp_max_size.len() == 1 {
+        temp_max_size[0]
+    } else {
+        temp_max_size[1]
+    };
+
+    let temp_width_op = add_op_space(temp_width);
+    let tmep_width_f = if temp_width.contains("%") {
+        temp_width.to_string()
+    } else {
+        format!("{}{}", temp_width, "px")
+    };
+    let temp_height_op = add_op_space(temp_height);
+    let temp_height_f = if temp_height.contains("%") {
+        temp_height.to_string()
+    } else {
+        format!("{}{}", temp_height, "px")
+    };
+
+    let temp_h_width_op = add_op_space(temp_h_width);
+    let tmep_h_width_f = if temp_h_width.contains("%") {
+        temp_h_width.to_string()
+    } else {
+        format!("{}{}", temp_h_width, "px")
+    };
+    let temp_h_height_op = add_op_space(temp_h_height);
+    let temp_h_height_f = if temp_h_height.contains("%") {
+        temp_h_height.to_string()
+    } else {
+        format!("{}{}", temp_h_height, "px")
+    };
+
+    let temp_min_width_op = add_op_space(temp_min_width);
+    let tmep_min_width_f = if temp_min_width.contains("%") {
+        temp_min_width.to_string()
+    } else {
+        format!("{}{}", temp_min_width, "px")
+    };
+    let temp_min_height_op = add_op_space(temp_min_height);
+    let temp_min_height_f = if temp_min_height.contains("%") {
+        temp_min_height.to_string()
+    } else {
+        format!("{}{}", temp_min_height, "px")
+    };
+    let temp_max_width_op = add_op_space(temp_max_width);
+    let tmep_max_width_f = if temp_max_width.contains("%") {
+        temp_max_width.to_string()
+    } else {
+        format!("{}{}", temp_max_width, "px")
+    };
+    let temp_max_height_op = add_op_space(temp_max_height);
+    let temp_max_height_f = if temp_max_height.contains("%") {
+        temp_max_height.to_string()
+    } else {
+        format!("{}{}", temp_max_height, "px")
+    };
+
+    let temp_padding = box_css_p.padding.as_str();
+    let temp_margin = box_css_p.margin.as_str();
+
+    let temp_padding_c = temp_padding
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let padding_value = if is_have_unit(temp_padding) {
+        temp_padding.to_owned()
+    } else {
+        temp_padding_c
+    };
+    let temp_margin_c = temp_margin
+        .split(" ")
+        .map(|x| {
+            if x.contains("%") || is_have_unit(x) {
+                x.to_string()
+            } else {
+                x.to_string() + "px"
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let margin_value = if is_have_unit(temp_margin) {
+        temp_margin.to_owned()
+    } else {
+        temp_margin_c
+    };
+
+    TextCss {
+        display: box_css_p.display.get_name(),
+        width: if temp_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_width) {
+            temp_width_op
+        } else {
+            tmep_width_f
+        },
+        height: if temp_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_height) {
+            temp_height_op
+        } else {
+            temp_height_f
+        },
+        padding: padding_value.clone(),
+        margin: margin_value.clone(),
+
+        background_image: if box_css_p.bg_image == "0" {
+            "none".to_owned()
+        } else {
+            if box_css_p.bg_image.contains("linear-gradient") {
+                box_css_p.bg_image.clone()
+            } else {
+                "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
+            }
+        },
+        cursor: box_css_p.cursor.get_name(),
+        white_space: box_css_p.white_space.get_name(),
+        min_width: if temp_min_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_min_width) {
+            temp_min_width_op
+        } else {
+            tmep_min_width_f
+        },
+        min_height: if temp_min_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_min_height) {
+            temp_min_height_op
+        } else {
+            temp_min_height_f
+        },
+        max_width: if temp_max_width == "auto" {
+            "none".to_owned()
+        } else if is_have_unit(temp_max_width) {
+            temp_max_width_op
+        } else {
+            tmep_max_width_f
+        },
+        max_height: if temp_max_height == "auto" {
+            "none".to_owned()
+        } else if is_have_unit(temp_max_height) {
+            temp_max_height_op
+        } else {
+            temp_max_height_f
+        },
+        z_index: box_css_p.z_index.clone(),
+        opacity: box_css_p.opacity.clone(),
+
+        font_size: if is_have_unit(&box_css_p.font_size) {
+            box_css_p.font_size.to_owned()
+        } else {
+            box_css_p.font_size.clone() + "px"
+        },
+        color: box_css_p.color.clone(),
+        font_style: box_css_p.font_style.get_name(),
+        font_weight: box_css_p.font_weight.get_name(),
+        letter_spacing: if is_have_unit(&box_css_p.letter_spacing) {
+            box_css_p.letter_spacing.to_owned()
+        } else {
+            box_css_p.letter_spacing.clone() + "px"
+        },
+        line_height: if is_have_unit(&box_css_p.line_height) {
+            box_css_p.line_height.to_owned()
+        } else {
+            box_css_p.line_height.clone() + "px"
+        },
+        text_decoration: box_css_p.text_decoration.clone(),
+        text_align: box_css_p.text_align.get_name(),
+        word_break: box_css_p.word_break.get_name(),
+
+        duration: box_css_p.duration.clone(),
+        timing_fn: box_css_p.timing_fn.get_name(),
+        hover_opacity: if box_css_p.h_opacity == String::default() {
+            box_css_p.opacity.clone()
+        } else {
+            box_css_p.h_opacity.clone()
+        },
+        hover_padding: if box_css_p.h_padding == String::default() {
+            padding_value
+        } else {
+            let temp = box_css_p.h_padding.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_margin: if box_css_p.h_margin == String::default() {
+            margin_value
+        } else {
+            let temp = box_css_p.h_margin.as_str();
+            if is_have_unit(temp) {
+                temp.to_string()
+            } else {
+                temp.split(" ")
+                    .map(|x| {
+                        if x.contains("%") || is_have_unit(x) {
+                            x.to_string()
+                        } else {
+                            x.to_string() + "px"
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            }
+        },
+        hover_color: if box_css_p.h_color == String::default() {
+            "none".to_string()
+        } else {
+            box_css_p.h_color.clone()
+        },
+        hover_width: if temp_h_width == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_width) {
+            temp_h_width_op
+        } else {
+            tmep_h_width_f
+        },
+        hover_height: if temp_h_height == "auto" {
+            "auto".to_owned()
+        } else if is_have_unit(temp_h_height) {
+            temp_h_height_op
+        } else {
+            temp_h_height_f
+        },
+        dark_color: if box_css_p.d_color == String::default() {
+            box_css_p.color.clone()
+        } else {
+            box_css_p.d_color.clone()
+        },
+    }
+}
+
 #[derive(Properties, Clone, PartialEq)]
 pub struct TextProps {
-    #[prop_or(Display::Block)]
-    pub display: Display,
-    #[prop_or(String::from("auto auto"))]
-    pub size: String,
-    #[prop_or(String::from("0"))]
-    pub padding: String,
-    #[prop_or(String::from("0"))]
-    pub margin: String,
-
-    #[prop_or(String::from("0"))]
-    pub bg_image: String,
-
-    #[prop_or(Cursor::Unset)]
-    pub cursor: Cursor,
-    #[prop_or(WhiteSpace::Normal)]
-    pub white_space: WhiteSpace,
-    #[prop_or(String::from("auto auto"))]
-    pub min_size: String,
-    #[prop_or(String::from("auto auto"))]
-    pub max_size: String,
-
-    #[prop_or(String::from("auto"))]
-    pub z_index: String,
-    #[prop_or(String::from("inherit"))]
-    pub opacity: String,
-
-    #[prop_or(String::from("medium"))]
-    pub font_size: String,
-    #[prop_or(String::from("#181818"))]
-    pub color: String,
-    #[prop_or(FontStyle::Normal)]
-    pub font_style: FontStyle,
-    #[prop_or(FontWeight::Normal)]
-    pub font_weight: FontWeight,
-    #[prop_or(String::from("normal"))]
-    pub letter_spacing: String,
-    #[prop_or(String::from("normal"))]
-    pub line_height: String,
-    #[prop_or(String::from("none"))]
-    pub text_decoration: String,
-    #[prop_or(TextAlign::Left)]
-    pub text_align: TextAlign,
-    #[prop_or(WordBreak::Normal)]
-    pub word_break: WordBreak,
-
-    #[prop_or(String::from("0"))]
-    pub duration: String,
-    #[prop_or(TimingFn::Ease)]
-    pub timing_fn: TimingFn,
-    #[prop_or(String::from(""))]
-    pub h_opacity: String,
-    #[prop_or(String::from(""))]
-    pub h_padding: String,
     #[prop_or(String::from(""))]
-    pub h_margin: String,
+    pub preset: String,
     #[prop_or(String::from(""))]
-    pub h_color: String,
-    #[prop_or(String::from(""))]
-    pub h_size: String,
+    pub sx: String,
+    #[prop_or_default]
+    pub display: Option<Display>,
+    #[prop_or_default]
+    pub size: Size,
+    #[prop_or_default]
+    pub padding: Spacing,
+    #[prop_or_default]
+    pub margin: Spacing,
+
+    #[prop_or_default]
+    pub bg_image: Option<String>,
+
+    #[prop_or_default]
+    pub cursor: Option<Cursor>,
+    #[prop_or_default]
+    pub white_space: Option<WhiteSpace>,
+    #[prop_or_default]
+    pub min_size: Size,
+    #[prop_or_default]
+    pub max_size: Size,
+
+    #[prop_or_default]
+    pub z_index: ZIndex,
+    #[prop_or_default]
+    pub opacity: Opacity,
+
+    #[prop_or_default]
+    pub font_size: Option<String>,
+    #[prop_or_default]
+    pub color: Option<String>,
+    #[prop_or_default]
+    pub font_style: Option<FontStyle>,
+    #[prop_or_default]
+    pub font_weight: Option<FontWeight>,
+    #[prop_or_default]
+    pub letter_spacing: Option<String>,
+    #[prop_or_default]
+    pub line_height: Option<String>,
+    #[prop_or_default]
+    pub text_decoration: Option<String>,
+    #[prop_or_default]
+    pub text_align: Option<TextAlign>,
+    #[prop_or_default]
+    pub word_break: Option<WordBreak>,
+
+    #[prop_or_default]
+    pub duration: Duration,
+    #[prop_or_default]
+    pub timing_fn: Option<TimingFn>,
+    #[prop_or_default]
+    pub h_opacity: Opacity,
+    #[prop_or_default]
+    pub h_padding: Spacing,
+    #[prop_or_default]
+    pub h_margin: Spacing,
+    #[prop_or_default]
+    pub h_color: Option<String>,
+    #[prop_or_default]
+    pub h_size: Size,
+
+    #[prop_or_default]
+    pub d_color: Option<String>,
 
     #[prop_or(String::from(""))]
-    pub d_color: String,
+    pub testid: String,
 
     #[prop_or_default]
     pub children: Children,
@@ -160,54 +494,74 @@ pub struct TextProps {
     pub node: NodeRef,
 }
 
-/// ### 使用示例
-///
-///```
-/// size: String,
-/// padding: String,
-/// margin: String,
-/// bg_image: String,  // linear-gradient, 文字渐变
-/// cursor: Cursor,
-/// white_space: WhiteSpace,
-/// min_size: String,
-/// max_size: String,
-/// z_index: String,
-/// opacity: String,
-/// font_size: String,
-/// color: String,
-/// font_style: FontStyle,
-/// font_weight: FontWeight,
-/// letter_spacing: String,
-/// line_height: String,
-/// text_decoration: String,
-/// text_align: TextAlign,
-/// word_break: WordBreak,
-/// duration: String,
-/// timing_fn: TimingFn, // transition 的动画方式
-/// h_opacity: String,  //hover 样式 "0.7"
-/// h_padding: String,  //hover 样式 "0 0 12 12"
-/// h_margin: String,  //hover 样式 "0 0 12 12"
-/// h_color: String, // hover 时的文字颜色
-/// h_size: String,  // hover 时的长宽
-/// d_color: String, // dark 模式
-///```
-#[function_component]
-pub fn Text(props: &TextProps) -> Html {
-    let box_css_p: TextCssProps = TextCssProps {
+resolved_props!(
+    ResolvedTextProps for TextProps {
+        display: Display = Display::Block,
+        size: Size = Size::from("auto auto"),
+        padding: Spacing = Spacing::from("0"),
+        margin: Spacing = Spacing::from("0"),
+        bg_image: String = String::from("0"),
+        cursor: Cursor = Cursor::Unset,
+        white_space: WhiteSpace = WhiteSpace::Normal,
+        min_size: Size = Size::from("auto auto"),
+        max_size: Size = Size::from("auto auto"),
+        z_index: ZIndex = ZIndex::from("auto"),
+        opacity: Opacity = Opacity::from("inherit"),
+        font_size: String = String::from("medium"),
+        color: String = String::from("#181818"),
+        font_style: FontStyle = FontStyle::Normal,
+        font_weight: FontWeight = FontWeight::Normal,
+        letter_spacing: String = String::from("normal"),
+        line_height: String = String::from("normal"),
+        text_decoration: String = String::from("none"),
+        text_align: TextAlign = TextAlign::Left,
+        word_break: WordBreak = WordBreak::Normal,
+        duration: Duration = Duration::from("0"),
+        timing_fn: TimingFn = TimingFn::Ease,
+        h_opacity: Opacity = Opacity::from(""),
+        h_padding: Spacing = Spacing::from(""),
+        h_margin: Spacing = Spacing::from(""),
+        h_color: String = String::from(""),
+        h_size: Size = Size::from(""),
+        d_color: String = String::from(""),
+    }
+    preset: String,
+    sx: String,
+    testid: String,
+    children: Children,
+    onclick: Callback<MouseEvent>,
+    node: NodeRef,
+);
+
+/// 组件生成的 css，可在原生环境中调用（如测试）
+/// 断点、设计稿换算、sx 的主题使用默认值，不含 ConfigProvider 和 preset
+pub fn text_css(props: &TextProps) -> String {
+    let breakpoints = Breakpoints::default();
+    let sx = SxStyle::parse(&props.sx, &Theme::default(), &breakpoints);
+    let props = resolve_text_props(props, &sx, None);
+    build_text_css(
+        &get_text_css_props(&props),
+        &breakpoints,
+        &Design::default(),
+    )
+}
+
+fn get_text_css_props(props: &ResolvedTextProps) -> TextCssProps {
+    TextCssProps {
         display: props.display.clone(),
-        size: props.size.clone(),
-        padding: props.padding.clone(),
-        margin: props.margin.clone(),
+        size: props.size.to_string(),
+        padding: props.padding.to_string(),
+        margin: props.margin.to_string(),
 
         bg_image: props.bg_image.clone(),
 
         cursor: props.cursor.clone(),
         white_space: props.white_space.clone(),
-        min_size: props.min_size.clone(),
-        max_size: props.max_size.clone(),
+        min_size: props.min_size.to_string(),
+        max_size: props.max_size.to_string(),
 
-        z_index: props.z_index.clone(),
-        opacity: props.opacity.clone(),
+        z_index: props.z_index.to_string(),
+        opacity: props.opacity.to_string(),
 
         font_size: props.font_size.clone(),
         color: props.color.clone(),
@@ -219,379 +573,169 @@ pub fn Text(props: &TextProps) -> Html {
         text_align: props.text_align.clone(),
         word_break: props.word_break.clone(),
 
-        duration: props.duration.clone(),
+        duration: props.duration.to_string(),
         timing_fn: props.timing_fn.clone(),
-        h_opacity: props.h_opacity.clone(),
-        h_padding: props.h_padding.clone(),
-        h_margin: props.h_margin.clone(),
+        h_opacity: props.h_opacity.to_string(),
+        h_padding: props.h_padding.to_string(),
+        h_margin: props.h_margin.to_string(),
         h_color: props.h_color.clone(),
-        h_size: props.h_size.clone(),
+        h_size: props.h_size.to_string(),
 
         d_color: props.d_color.clone(),
-    };
-
-    let box_css = use_memo(
-        |box_css_p| {
-            let temp_size = box_css_p.size.split(" ").collect::<Vec<&str>>();
-            let temp_h_size = if box_css_p.h_size == String::default() {
-                temp_size.clone()
-            } else {
-                box_css_p.h_size.split(" ").collect::<Vec<&str>>()
-            };
-            let temp_min_size = box_css_p.min_size.split(" ").collect::<Vec<&str>>();
-            let temp_max_size = box_css_p.max_size.split(" ").collect::<Vec<&str>>();
-
-            let temp_width = temp_size[0];
-            let temp_height = if temp_size.len() == 1 {
-                temp_size[0]
-            } else {
-                temp_size[1]
-            };
-
-            let temp_h_width = temp_h_size[0];
-            let temp_h_height = if temp_h_size.len() == 1 {
-                temp_h_size[0]
-            } else {
-                temp_h_size[1]
-            };
-
-            let temp_min_width = temp_min_size[0];
-            let temp_min_height = if temp_min_size.len() == 1 {
-                temp_min_size[0]
-            } else {
-                temp_min_size[1]
-            };
-            let temp_max_width = temp_max_size[0];
-            let temp_max_height = if temp_max_size.len() == 1 {
-                temp_max_size[0]
-            } else {
-                temp_max_size[1]
-            };
+    }
+}
 
-            let temp_width_op = add_op_space(temp_width);
-            let tmep_width_f = if temp_width.contains("%") {
-                format!("{}", temp_width)
-            } else {
-                format!("{}{}", temp_width, "px")
-            };
-            let temp_height_op = add_op_space(temp_height);
-            let temp_height_f = if temp_height.contains("%") {
-                format!("{}", temp_height)
-            } else {
-                format!("{}{}", temp_height, "px")
-            };
+/// 依次使用内置默认值、ConfigProvider、preset 和 sx、组件中设置的属性
+fn resolve_text_props(
+    props: &TextProps,
+    sx: &SxStyle,
+    config: Option<&TextProps>,
+) -> ResolvedTextProps {
+    let mut p = ResolvedTextProps::new(props, config);
+    apply_text_sx(&mut p, props, sx);
+    p.apply(props);
+    p
+}
 
-            let temp_h_width_op = add_op_space(temp_h_width);
-            let tmep_h_width_f = if temp_h_width.contains("%") {
-                format!("{}", temp_h_width)
-            } else {
-                format!("{}{}", temp_h_width, "px")
-            };
-            let temp_h_height_op = add_op_space(temp_h_height);
-            let temp_h_height_f = if temp_h_height.contains("%") {
-                format!("{}", temp_h_height)
-            } else {
-                format!("{}{}", temp_h_height, "px")
-            };
+fn apply_text_sx(p: &mut ResolvedTextProps, props: &TextProps, sx: &SxStyle) {
+    sx_props!(
+        p,
+        props,
+        sx,
+        display,
+        size,
+        padding,
+        margin,
+        bg_image,
+        min_size,
+        max_size,
+        z_index,
+        opacity,
+        font_size,
+        color,
+        letter_spacing,
+        line_height,
+        text_decoration,
+        duration,
+        h_opacity,
+        h_padding,
+        h_margin,
+        h_color,
+        h_size,
+        d_color
+    );
+}
 
-            let temp_min_width_op = add_op_space(temp_min_width);
-            let tmep_min_width_f = if temp_min_width.contains("%") {
-                format!("{}", temp_min_width)
-            } else {
-                format!("{}{}", temp_min_width, "px")
-            };
-            let temp_min_height_op = add_op_space(temp_min_height);
-            let temp_min_height_f = if temp_min_height.contains("%") {
-                format!("{}", temp_min_height)
-            } else {
-                format!("{}{}", temp_min_height, "px")
-            };
-            let temp_max_width_op = add_op_space(temp_max_width);
-            let tmep_max_width_f = if temp_max_width.contains("%") {
-                format!("{}", temp_max_width)
-            } else {
-                format!("{}{}", temp_max_width, "px")
-            };
-            let temp_max_height_op = add_op_space(temp_max_height);
-            let temp_max_height_f = if temp_max_height.contains("%") {
-                format!("{}", temp_max_height)
-            } else {
-                format!("{}{}", temp_max_height, "px")
-            };
-
-            let temp_padding = box_css_p.padding.as_str();
-            let temp_margin = box_css_p.margin.as_str();
-
-            let temp_padding_c = temp_padding
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let padding_value = if is_have_unit(temp_padding) {
-                temp_padding.to_owned()
-            } else {
-                temp_padding_c
-            };
-            let temp_margin_c = temp_margin
-                .split(" ")
-                .map(|x| {
-                    if x.contains("%") {
-                        x.to_string()
-                    } else {
-                        x.to_string() + "px"
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(" ");
-            let margin_value = if is_have_unit(temp_margin) {
-                temp_margin.to_owned()
-            } else {
-                temp_margin_c
-            };
-
-            TextCss {
-                display: box_css_p.display.get_name(),
-                width: if temp_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_width) {
-                    temp_width_op
-                } else {
-                    tmep_width_f
-                },
-                height: if temp_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_height) {
-                    temp_height_op
-                } else {
-                    temp_height_f
-                },
-                padding: padding_value.clone(),
-                margin: margin_value.clone(),
-
-                background_image: if box_css_p.bg_image == "0" {
-                    "none".to_owned()
-                } else {
-                    if box_css_p.bg_image.contains("linear-gradient") {
-                        box_css_p.bg_image.clone()
-                    } else {
-                        "url(".to_string() + box_css_p.bg_image.clone().as_str() + ")"
-                    }
-                },
-                cursor: box_css_p.cursor.get_name(),
-                white_space: box_css_p.white_space.get_name(),
-                min_width: if temp_min_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_min_width) {
-                    temp_min_width_op
-                } else {
-                    tmep_min_width_f
-                },
-                min_height: if temp_min_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_min_height) {
-                    temp_min_height_op
-                } else {
-                    temp_min_height_f
-                },
-                max_width: if temp_max_width == "auto" {
-                    "none".to_owned()
-                } else if is_have_unit(temp_max_width) {
-                    temp_max_width_op
-                } else {
-                    tmep_max_width_f
-                },
-                max_height: if temp_max_height == "auto" {
-                    "none".to_owned()
-                } else if is_have_unit(temp_max_height) {
-                    temp_max_height_op
-                } else {
-                    temp_max_height_f
-                },
-                z_index: box_css_p.z_index.clone(),
-                opacity: box_css_p.opacity.clone(),
-
-                font_size: if is_have_unit(&box_css_p.font_size) {
-                    box_css_p.font_size.to_owned()
-                } else {
-                    box_css_p.font_size.clone() + "px"
-                },
-                color: box_css_p.color.clone(),
-                font_style: box_css_p.font_style.get_name(),
-                font_weight: box_css_p.font_weight.get_name(),
-                letter_spacing: if is_have_unit(&box_css_p.letter_spacing) {
-                    box_css_p.letter_spacing.to_owned()
-                } else {
-                    box_css_p.letter_spacing.clone() + "px"
-                },
-                line_height: if is_have_unit(&box_css_p.line_height) {
-                    box_css_p.line_height.to_owned()
-                } else {
-                    box_css_p.line_height.clone() + "px"
-                },
-                text_decoration: box_css_p.text_decoration.clone(),
-                text_align: box_css_p.text_align.get_name(),
-                word_break: box_css_p.word_break.get_name(),
-
-                duration: box_css_p.duration.clone(),
-                timing_fn: box_css_p.timing_fn.get_name(),
-                hover_opacity: if box_css_p.h_opacity == String::default() {
-                    box_css_p.opacity.clone()
-                } else {
-                    box_css_p.h_opacity.clone()
-                },
-                hover_padding: if box_css_p.h_padding == String::default() {
-                    padding_value
-                } else {
-                    let temp = box_css_p.h_padding.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_margin: if box_css_p.h_margin == String::default() {
-                    margin_value
-                } else {
-                    let temp = box_css_p.h_margin.as_str();
-                    if is_have_unit(temp) {
-                        temp.to_string()
-                    } else {
-                        temp.split(" ")
-                            .map(|x| {
-                                if x.contains("%") {
-                                    x.to_string()
-                                } else {
-                                    x.to_string() + "px"
-                                }
-                            })
-                            .collect::<Vec<String>>()
-                            .join(" ")
-                    }
-                },
-                hover_color: if box_css_p.h_color == String::default() {
-                    "none".to_string()
-                } else {
-                    box_css_p.h_color.clone()
-                },
-                hover_width: if temp_h_width == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_width) {
-                    temp_h_width_op
-                } else {
-                    tmep_h_width_f
-                },
-                hover_height: if temp_h_height == "auto" {
-                    "auto".to_owned()
-                } else if is_have_unit(temp_h_height) {
-                    temp_h_height_op
-                } else {
-                    temp_h_height_f
-                },
-                dark_color: if box_css_p.d_color == String::default() {
-                    box_css_p.color.clone()
-                } else {
-                    box_css_p.d_color.clone()
-                },
-            }
-        },
+fn build_text_css(box_css_p: &TextCssProps, breakpoints: &Breakpoints, design: &Design) -> String {
+    let box_css_p = &design_props!(
+        box_css_p,
+        design,
+        size,
+        padding,
+        margin,
+        min_size,
+        max_size,
+        font_size,
+        letter_spacing,
+        line_height,
+        h_padding,
+        h_margin,
+        h_size
+    );
+    let (base, points) = responsive_props!(
         box_css_p,
+        breakpoints,
+        size,
+        padding,
+        margin,
+        bg_image,
+        min_size,
+        max_size,
+        z_index,
+        opacity,
+        font_size,
+        color,
+        letter_spacing,
+        line_height,
+        text_decoration,
+        duration,
+        h_opacity,
+        h_padding,
+        h_margin,
+        h_color,
+        h_size,
+        d_color,
     );
+    let points = points
+        .iter()
+        .map(|(name, p)| (name.clone(), get_text_css(p).to_blocks()))
+        .collect();
+    responsive_css(&get_text_css(&base).to_blocks(), points, breakpoints)
+}
 
-    let class = css!(
-        r#"
-            display: ${display};
-            width: ${width};
-            height: ${height};
-            padding: ${padding};
-            margin: ${margin};
-
-            background-image: ${bg_img};
-            cursor: ${cursor};
-            white-space: ${white_space};
-            min-width: ${min_width};
-            min-height: ${min_height};
-            max-width: ${max_width};
-            max-height: ${max_height};
-            z-index: ${z_index};
-            opacity: ${opacity};
-
-            font-size: ${font_size};
-            color: ${color};
-            font-style: ${font_style};
-            font-weight: ${font_weight};
-            letter-spacing: ${letter_spacing};
-            line-height: ${line_height};
-            text-decoration: ${text_decoration};
-            text-align: ${text_align};
-            word-break: ${word_break};
-
-            transition: all ${duration}s ${timing_fn};
-            &:hover {
-                color: ${hover_color};
-                width: ${hover_width};
-                height: ${hover_height};
-                padding: ${hover_padding};
-                margin: ${hover_margin};
-                opacity: ${hover_opacity};
-            }
+/// ### 使用示例
+///
+///```text
+/// size: Size,
+/// padding: Spacing,
+/// margin: Spacing,
+/// bg_image: String,  // linear-gradient, 文字渐变
+/// cursor: Cursor,
+/// white_space: WhiteSpace,
+/// min_size: Size,
+/// max_size: Size,
+/// z_index: ZIndex,
+/// opacity: Opacity,
+/// font_size: String,
+/// color: String,
+/// font_style: FontStyle,
+/// font_weight: FontWeight,
+/// letter_spacing: String,
+/// line_height: String,
+/// text_decoration: String,
+/// text_align: TextAlign,
+/// word_break: WordBreak,
+/// duration: Duration,
+/// timing_fn: TimingFn, // transition 的动画方式
+/// h_opacity: Opacity,  //hover 样式 "0.7"
+/// h_padding: Spacing,  //hover 样式 "0 0 12 12"
+/// h_margin: Spacing,  //hover 样式 "0 0 12 12"
+/// h_color: String, // hover 时的文字颜色
+/// h_size: Size,  // hover 时的长宽
+/// d_color: String, // dark 模式
+/// testid: String, // 渲染为 data-testid
+///```
+#[function_component]
+pub fn Text(props: &TextProps) -> Html {
+    let config = use_config();
+    let breakpoints = use_breakpoints();
+    let sx = use_memo(
+        |(preset, sx, config, breakpoints)| {
+            let sx = format!("{} {}", config.expand_presets(preset), sx);
+            SxStyle::parse(&sx, &config.get_theme(), breakpoints)
+        },
+        (
+            props.preset.clone(),
+            props.sx.clone(),
+            config.clone(),
+            breakpoints.clone(),
+        ),
+    );
+    let props = &resolve_text_props(props, &sx, config.text_props.as_deref());
+    let box_css_p = get_text_css_props(props);
 
-            @media (prefers-color-scheme: dark) {
-              & {
-                color: ${dark_color};
-              }
-            }
-        "#,
-        display = box_css.display,
-        width = box_css.width,
-        height = box_css.height,
-        padding = box_css.padding,
-        margin = box_css.margin,
-        bg_img = box_css.background_image,
-        cursor = box_css.cursor,
-        white_space = box_css.white_space,
-        min_width = box_css.min_width,
-        min_height = box_css.min_height,
-        max_width = box_css.max_width,
-        max_height = box_css.max_height,
-        z_index = box_css.z_index,
-        opacity = box_css.opacity,
-        font_size = box_css.font_size,
-        color = box_css.color,
-        font_style = box_css.font_style,
-        font_weight = box_css.font_weight,
-        letter_spacing = box_css.letter_spacing,
-        line_height = box_css.line_height,
-        text_decoration = box_css.text_decoration,
-        text_align = box_css.text_align,
-        word_break = box_css.word_break,
-        duration = box_css.duration,
-        timing_fn = box_css.timing_fn,
-        hover_opacity = box_css.hover_opacity,
-        hover_padding = box_css.hover_padding,
-        hover_margin = box_css.hover_margin,
-        hover_color = box_css.hover_color,
-        hover_width = box_css.hover_width,
-        hover_height = box_css.hover_height,
-        dark_color = box_css.dark_color,
+    let design = use_design();
+    let style = use_memo(
+        |(box_css_p, breakpoints, design)| {
+            style_source(build_text_css(box_css_p, breakpoints, design))
+        },
+        (box_css_p, breakpoints, design),
     );
+    let class = (*style).clone();
 
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
     html! {
-        <span {class} onclick={props.onclick.clone()} ref={props.node.clone()}>
+        <span {class} data-testid={testid} onclick={props.onclick.clone()} ref={props.node.clone()}>
         { for props.children.iter() }
         </span>
     }
@@ -0,0 +1,165 @@
+/// sx 工具类使用的主题刻度
+/// spacing 为 p-1 对应的 px，colors 如 ("primary", "#3b82f6")、("primary-600", "#2563eb")
+#[derive(Clone, PartialEq)]
+pub struct Theme {
+    pub spacing: f64,
+    pub colors: Vec<(String, String)>,
+    pub font_sizes: Vec<(String, String)>,
+    pub radius: Vec<(String, String)>,
+    pub shadows: Vec<(String, String)>,
+}
+impl Theme {
+    /// Theme::default().with_colors(vec![("primary", "#1677ff"), ("primary-600", "#0958d9")])
+    pub fn with_colors(mut self, colors: Vec<(&str, &str)>) -> Self {
+        for (name, value) in colors {
+            self.colors.retain(|(n, _)| n != name);
+            self.colors.push((name.to_owned(), value.to_owned()));
+        }
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: f64) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn get_color(&self, name: &str) -> Option<String> {
+        find(&self.colors, name)
+    }
+
+    pub fn get_font_size(&self, name: &str) -> Option<String> {
+        find(&self.font_sizes, name)
+    }
+
+    /// "" 为 rounded 的默认值
+    pub fn get_radius(&self, name: &str) -> Option<String> {
+        find(&self.radius, name)
+    }
+
+    /// "" 为 shadow 的默认值
+    pub fn get_shadow(&self, name: &str) -> Option<String> {
+        find(&self.shadows, name)
+    }
+}
+impl Default for Theme {
+    fn default() -> Self {
+        let mut colors = to_pairs(vec![
+            ("white", "#fff"),
+            ("black", "#000"),
+            ("transparent", "transparent"),
+            ("current", "currentColor"),
+        ]);
+        for (name, shades) in PALETTES {
+            for (shade, value) in SHADES.iter().zip(shades.iter()) {
+                colors.push((format!("{}-{}", name, shade), value.to_string()));
+            }
+            colors.push((name.to_string(), shades[5].to_string()));
+        }
+        Theme {
+            spacing: 4.0,
+            colors,
+            font_sizes: to_pairs(vec![
+                ("xs", "12"),
+                ("sm", "14"),
+                ("base", "16"),
+                ("lg", "18"),
+                ("xl", "20"),
+                ("2xl", "24"),
+                ("3xl", "30"),
+                ("4xl", "36"),
+            ]),
+            radius: to_pairs(vec![
+                ("none", "0"),
+                ("sm", "2"),
+                ("", "4"),
+                ("md", "6"),
+                ("lg", "8"),
+                ("xl", "12"),
+                ("2xl", "16"),
+                ("3xl", "24"),
+                ("full", "9999"),
+            ]),
+            shadows: to_pairs(vec![
+                ("none", "none"),
+                ("sm", "0 1px 2px 0 rgba(0, 0, 0, 0.05)"),
+                (
+                    "",
+                    "0 1px 3px 0 rgba(0, 0, 0, 0.1), 0 1px 2px -1px rgba(0, 0, 0, 0.1)",
+                ),
+                (
+                    "md",
+                    "0 4px 6px -1px rgba(0, 0, 0, 0.1), 0 2px 4px -2px rgba(0, 0, 0, 0.1)",
+                ),
+                (
+                    "lg",
+                    "0 10px 15px -3px rgba(0, 0, 0, 0.1), 0 4px 6px -4px rgba(0, 0, 0, 0.1)",
+                ),
+                (
+                    "xl",
+                    "0 20px 25px -5px rgba(0, 0, 0, 0.1), 0 8px 10px -6px rgba(0, 0, 0, 0.1)",
+                ),
+                ("2xl", "0 25px 50px -12px rgba(0, 0, 0, 0.25)"),
+            ]),
+        }
+    }
+}
+
+const SHADES: [u32; 10] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+/// 默认色板，primary 与 blue 相同，不带色阶时为 500
+const PALETTES: [(&str, [&str; 10]); 6] = [
+    (
+        "primary",
+        [
+            "#eff6ff", "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb", "#1d4ed8",
+            "#1e40af", "#1e3a8a",
+        ],
+    ),
+    (
+        "gray",
+        [
+            "#f9fafb", "#f3f4f6", "#e5e7eb", "#d1d5db", "#9ca3af", "#6b7280", "#4b5563", "#374151",
+            "#1f2937", "#111827",
+        ],
+    ),
+    (
+        "red",
+        [
+            "#fef2f2", "#fee2e2", "#fecaca", "#fca5a5", "#f87171", "#ef4444", "#dc2626", "#b91c1c",
+            "#991b1b", "#7f1d1d",
+        ],
+    ),
+    (
+        "yellow",
+        [
+            "#fefce8", "#fef9c3", "#fef08a", "#fde047", "#facc15", "#eab308", "#ca8a04", "#a16207",
+            "#854d0e", "#713f12",
+        ],
+    ),
+    (
+        "green",
+        [
+            "#f0fdf4", "#dcfce7", "#bbf7d0", "#86efac", "#4ade80", "#22c55e", "#16a34a", "#15803d",
+            "#166534", "#14532d",
+        ],
+    ),
+    (
+        "blue",
+        [
+            "#eff6ff", "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb", "#1d4ed8",
+            "#1e40af", "#1e3a8a",
+        ],
+    ),
+];
+
+fn to_pairs(list: Vec<(&str, &str)>) -> Vec<(String, String)> {
+    list.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+fn find(list: &[(String, String)], name: &str) -> Option<String> {
+    list.iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, value)| value.clone())
+}
@@ -0,0 +1,222 @@
+use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, Element, HtmlCanvasElement};
+use yew::{
+    function_component, html, use_effect_with_deps, use_memo, use_mut_ref, use_state_eq, Callback,
+    Html, MouseEvent, NodeRef, Properties,
+};
+
+use crate::prelude::{Size, TruncatePosition};
+use crate::utils::{is_have_unit, style_source, ResizeWatcher};
+
+/// 按 position 省略 text，使结果（含 ellipsis）的宽度不超过 max_width
+/// measure 返回文字的宽度，不需要省略时返回原文
+/// ### 使用示例
+///```
+/// # use yew_quick::prelude::{truncate_text, TruncatePosition};
+/// // 每个字符宽度为 1
+/// let text = truncate_text("very-long-file-name.pdf", &TruncatePosition::Middle, "…", 12.0, |x| x.chars().count() as f64);
+/// assert_eq!(text, "very-l…e.pdf");
+///```
+pub fn truncate_text(
+    text: &str,
+    position: &TruncatePosition,
+    ellipsis: &str,
+    max_width: f64,
+    measure: impl Fn(&str) -> f64,
+) -> String {
+    if measure(text) <= max_width {
+        return text.to_owned();
+    }
+    let chars = text.chars().collect::<Vec<char>>();
+    // 保留 keep 个字符时的结果
+    let build = |keep: usize| {
+        let (front, back) = match position {
+            TruncatePosition::Start => (0, keep),
+            TruncatePosition::Middle => (keep.div_ceil(2), keep / 2),
+            TruncatePosition::End => (keep, 0),
+        };
+        let mut result = chars[..front].iter().collect::<String>();
+        result.push_str(ellipsis);
+        result.extend(&chars[chars.len() - back..]);
+        result
+    };
+    // 二分查找能放下的最多字符数
+    let (mut low, mut high) = (0, chars.len().saturating_sub(1));
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if measure(&build(mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    build(low)
+}
+
+/// 以元素计算后的字体创建 canvas，用于测量文字宽度
+fn text_measurer(el: &Element) -> Option<impl Fn(&str) -> f64> {
+    let window = web_sys::window()?;
+    let style = window.get_computed_style(el).ok()??;
+    let value = |name: &str| style.get_property_value(name).unwrap_or_default();
+    // font 简写在部分浏览器中为空，按各项拼接
+    let font = ["font-style", "font-weight", "font-size", "font-family"]
+        .iter()
+        .map(|name| value(name))
+        .collect::<Vec<String>>()
+        .join(" ");
+    // letter-spacing 为 normal 时按 0 计算
+    let spacing = value("letter-spacing")
+        .trim_end_matches("px")
+        .parse::<f64>()
+        .unwrap_or(0.0);
+    let ctx = window
+        .document()?
+        .create_element("canvas")
+        .ok()?
+        .dyn_into::<HtmlCanvasElement>()
+        .ok()?
+        .get_context("2d")
+        .ok()??
+        .dyn_into::<CanvasRenderingContext2d>()
+        .ok()?;
+    ctx.set_font(&font);
+    Some(move |text: &str| {
+        ctx.measure_text(text).map(|m| m.width()).unwrap_or(0.0)
+            + spacing * text.chars().count() as f64
+    })
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct TruncateProps {
+    pub text: String,
+    #[prop_or(TruncatePosition::End)]
+    pub position: TruncatePosition,
+    #[prop_or(String::from("…"))]
+    pub ellipsis: String,
+    #[prop_or(true)]
+    pub title: bool,
+    #[prop_or(Size::from("100%"))]
+    pub width: Size,
+    #[prop_or(Size::from("inherit"))]
+    pub font_size: Size,
+    #[prop_or(String::from("inherit"))]
+    pub color: String,
+    #[prop_or(String::from(""))]
+    pub testid: String,
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub on_truncate: Callback<bool>,
+    #[prop_or_default]
+    pub node: NodeRef,
+}
+
+/// 单行文字按可用宽度省略，可省略开头、中间或结尾，省略时 title 为全文
+/// 宽度改变时重新计算，使用元素计算后的字体测量文字
+/// ### 使用示例
+///```text
+/// text: String,
+/// position: TruncatePosition, // Start Middle End，默认 End
+/// ellipsis: String, // 省略的部分替换为，默认 "…"
+/// title: bool, // 省略时是否将全文设置为 title，默认 true
+/// width: Size, // 默认 "100%"，数字为 px
+/// font_size: Size, // 默认继承，数字为 px
+/// color: String, // 默认继承
+/// testid: String, // 渲染为 data-testid
+/// on_truncate: Callback<bool>, // 是否省略
+///```
+#[function_component]
+pub fn Truncate(props: &TruncateProps) -> Html {
+    // (测量时的 text, 显示的文字)，text 改变后到重新测量前显示新的 text
+    let shown = use_state_eq(|| (props.text.clone(), props.text.clone()));
+    // 每次渲染更新，大小改变时使用最新的回调
+    let on_truncate = use_mut_ref(Callback::<bool>::default);
+    *on_truncate.borrow_mut() = props.on_truncate.clone();
+
+    {
+        let node = props.node.clone();
+        let shown = shown.clone();
+        let on_truncate = on_truncate.clone();
+        use_effect_with_deps(
+            move |(text, position, ellipsis)| {
+                let text = text.clone();
+                let position = position.clone();
+                let ellipsis = ellipsis.clone();
+                let mut last = None;
+                // 开始观察时会先回调一次
+                let watcher = node.cast::<Element>().and_then(|el| {
+                    let target = el.clone();
+                    ResizeWatcher::new(&el, move || {
+                        let measure = match text_measurer(&target) {
+                            Some(measure) => measure,
+                            None => return,
+                        };
+                        let value = truncate_text(
+                            &text,
+                            &position,
+                            &ellipsis,
+                            target.client_width() as f64,
+                            measure,
+                        );
+                        let truncated = value != text;
+                        shown.set((text.clone(), value));
+                        if last != Some(truncated) {
+                            last = Some(truncated);
+                            on_truncate.borrow().emit(truncated);
+                        }
+                    })
+                });
+                move || drop(watcher)
+            },
+            (
+                props.text.clone(),
+                props.position.clone(),
+                props.ellipsis.clone(),
+            ),
+        );
+    }
+
+    let size = |data: &String| {
+        if is_have_unit(data) || data.contains("%") {
+            data.to_owned()
+        } else {
+            format!("{}px", data)
+        }
+    };
+    // 测量前（如 SSR）使用 css 省略结尾
+    let style = use_memo(
+        |(width, font_size, color)| {
+            style_source(format!(
+                "display: block;
+width: {};
+max-width: 100%;
+overflow: hidden;
+white-space: nowrap;
+text-overflow: ellipsis;
+font-size: {};
+color: {};",
+                size(width),
+                size(font_size),
+                color
+            ))
+        },
+        (
+            props.width.to_string(),
+            props.font_size.to_string(),
+            props.color.clone(),
+        ),
+    );
+    let class = (*style).clone();
+    let shown = if shown.0 == props.text {
+        shown.1.clone()
+    } else {
+        props.text.clone()
+    };
+    let title = (props.title && shown != props.text).then(|| props.text.clone());
+    let testid = (!props.testid.is_empty()).then(|| props.testid.clone());
+    html! {
+        <span {class} {title} data-testid={testid} onclick={props.onclick.clone()} ref={props.node.clone()}>
+        { shown }
+        </span>
+    }
+}
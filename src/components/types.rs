@@ -5,9 +5,9 @@ pub enum BoxSizing {
 }
 impl BoxSizing {
     pub fn get_name(&self) -> String {
-        match &self {
-            &BoxSizing::BorderBox => "border-box".to_owned(),
-            &BoxSizing::ContentBox => "content-box".to_owned(),
+        match self {
+            BoxSizing::BorderBox => "border-box".to_owned(),
+            BoxSizing::ContentBox => "content-box".to_owned(),
         }
     }
 }
@@ -27,17 +27,17 @@ pub enum BorderStyle {
 }
 impl BorderStyle {
     pub fn get_name(&self) -> String {
-        match &self {
-            &BorderStyle::No => "none".to_owned(),
-            &BorderStyle::Hidden => "hidden".to_owned(),
-            &BorderStyle::Dotted => "dotted".to_owned(),
-            &BorderStyle::Dashed => "dashed".to_owned(),
-            &BorderStyle::Solid => "solid".to_owned(),
-            &BorderStyle::Double => "double".to_owned(),
-            &BorderStyle::Groove => "groove".to_owned(),
-            &BorderStyle::Ridge => "ridge".to_owned(),
-            &BorderStyle::Inset => "inset".to_owned(),
-            &BorderStyle::Outset => "outset".to_owned(),
+        match self {
+            BorderStyle::No => "none".to_owned(),
+            BorderStyle::Hidden => "hidden".to_owned(),
+            BorderStyle::Dotted => "dotted".to_owned(),
+            BorderStyle::Dashed => "dashed".to_owned(),
+            BorderStyle::Solid => "solid".to_owned(),
+            BorderStyle::Double => "double".to_owned(),
+            BorderStyle::Groove => "groove".to_owned(),
+            BorderStyle::Ridge => "ridge".to_owned(),
+            BorderStyle::Inset => "inset".to_owned(),
+            BorderStyle::Outset => "outset".to_owned(),
         }
     }
 }
@@ -58,18 +58,18 @@ pub enum Overflow {
 }
 impl Overflow {
     pub fn get_name(&self) -> String {
-        match &self {
-            &Overflow::Visible => "visible".to_owned(),
-            &Overflow::Hidden => "hidden".to_owned(),
-            &Overflow::Clip => "clip".to_owned(),
-            &Overflow::Scroll => "scroll".to_owned(),
-            &Overflow::Auto => "auto".to_owned(),
-            &Overflow::VisibleHidden => "visible hidden".to_owned(),
-            &Overflow::VisibleScroll => "visible scroll".to_owned(),
-            &Overflow::HiddenVisible => "hidden visible".to_owned(),
-            &Overflow::HiddenScroll => "hidden scroll".to_owned(),
-            &Overflow::ScrollVisible => "scroll visible".to_owned(),
-            &Overflow::ScrollHidden => "scroll hidden".to_owned(),
+        match self {
+            Overflow::Visible => "visible".to_owned(),
+            Overflow::Hidden => "hidden".to_owned(),
+            Overflow::Clip => "clip".to_owned(),
+            Overflow::Scroll => "scroll".to_owned(),
+            Overflow::Auto => "auto".to_owned(),
+            Overflow::VisibleHidden => "visible hidden".to_owned(),
+            Overflow::VisibleScroll => "visible scroll".to_owned(),
+            Overflow::HiddenVisible => "hidden visible".to_owned(),
+            Overflow::HiddenScroll => "hidden scroll".to_owned(),
+            Overflow::ScrollVisible => "scroll visible".to_owned(),
+            Overflow::ScrollHidden => "scroll hidden".to_owned(),
         }
     }
 }
@@ -82,10 +82,112 @@ pub enum ImageMode {
 }
 impl ImageMode {
     pub fn get_name(&self) -> String {
-        match &self {
-            &ImageMode::Auto => "auto".to_owned(),
-            &ImageMode::AspectFit => "contain".to_owned(),
-            &ImageMode::AspectFill => "cover".to_owned(),
+        match self {
+            ImageMode::Auto => "auto".to_owned(),
+            ImageMode::AspectFit => "contain".to_owned(),
+            ImageMode::AspectFill => "cover".to_owned(),
+        }
+    }
+}
+
+/// Image 的 mode，同小程序 image 组件
+/// WidthFix / HeightFix 保持宽度或高度不变，另一边按图片原始宽高比计算
+/// Top、Left、TopLeft 等不缩放图片，只显示对应的区域
+#[derive(Clone, PartialEq)]
+pub enum ImageFit {
+    ScaleToFill,
+    AspectFit,
+    AspectFill,
+    WidthFix,
+    HeightFix,
+    Top,
+    Bottom,
+    Center,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+impl ImageFit {
+    /// object-fit
+    pub fn get_name(&self) -> String {
+        match self {
+            ImageFit::ScaleToFill => "fill".to_owned(),
+            ImageFit::AspectFit => "contain".to_owned(),
+            ImageFit::AspectFill => "cover".to_owned(),
+            ImageFit::WidthFix => "fill".to_owned(),
+            ImageFit::HeightFix => "fill".to_owned(),
+            _ => "none".to_owned(),
+        }
+    }
+
+    /// object-position
+    pub fn get_position(&self) -> String {
+        match self {
+            ImageFit::Top => "center top".to_owned(),
+            ImageFit::Bottom => "center bottom".to_owned(),
+            ImageFit::Left => "left center".to_owned(),
+            ImageFit::Right => "right center".to_owned(),
+            ImageFit::TopLeft => "left top".to_owned(),
+            ImageFit::TopRight => "right top".to_owned(),
+            ImageFit::BottomLeft => "left bottom".to_owned(),
+            ImageFit::BottomRight => "right bottom".to_owned(),
+            _ => "center center".to_owned(),
+        }
+    }
+}
+
+/// Truncate 省略的位置
+#[derive(Clone, PartialEq)]
+pub enum TruncatePosition {
+    Start,
+    Middle,
+    End,
+}
+impl TruncatePosition {
+    pub fn get_name(&self) -> String {
+        match self {
+            TruncatePosition::Start => "start".to_owned(),
+            TruncatePosition::Middle => "middle".to_owned(),
+            TruncatePosition::End => "end".to_owned(),
+        }
+    }
+}
+
+/// Image 的懒加载方式
+/// Observer 进入可视区域（含 lazy_margin）后才设置 src，Native 使用 loading="lazy"
+#[derive(Clone, PartialEq)]
+pub enum LazyMode {
+    None,
+    Observer,
+    Native,
+}
+
+/// Image 加载完成前显示的占位
+/// Color 为背景色，Blur 为模糊显示的低分辨率图片地址，Skeleton 为骨架屏闪烁效果
+#[derive(Clone, PartialEq)]
+pub enum Placeholder {
+    None,
+    Color(String),
+    Blur(String),
+    Skeleton,
+}
+
+/// img 的 decoding 属性
+#[derive(Clone, PartialEq)]
+pub enum Decoding {
+    Auto,
+    Sync,
+    Async,
+}
+impl Decoding {
+    pub fn get_name(&self) -> String {
+        match self {
+            Decoding::Auto => "auto".to_owned(),
+            Decoding::Sync => "sync".to_owned(),
+            Decoding::Async => "async".to_owned(),
         }
     }
 }
@@ -102,14 +204,14 @@ pub enum TimingFn {
 }
 impl TimingFn {
     pub fn get_name(&self) -> String {
-        match &self {
-            &TimingFn::Ease => "ease".to_owned(),
-            &TimingFn::EaseIn => "ease-in".to_owned(),
-            &TimingFn::EaseOut => "ease-out".to_owned(),
-            &TimingFn::EaseInOut => "ease-in-out".to_owned(),
-            &TimingFn::Linear => "linear".to_owned(),
-            &TimingFn::StepStart => "step-start".to_owned(),
-            &TimingFn::StepEnd => "step-end".to_owned(),
+        match self {
+            TimingFn::Ease => "ease".to_owned(),
+            TimingFn::EaseIn => "ease-in".to_owned(),
+            TimingFn::EaseOut => "ease-out".to_owned(),
+            TimingFn::EaseInOut => "ease-in-out".to_owned(),
+            TimingFn::Linear => "linear".to_owned(),
+            TimingFn::StepStart => "step-start".to_owned(),
+            TimingFn::StepEnd => "step-end".to_owned(),
         }
     }
 }
@@ -129,17 +231,17 @@ pub enum Cursor {
 }
 impl Cursor {
     pub fn get_name(&self) -> String {
-        match &self {
-            &Cursor::Unset => "unset".to_owned(),
-            &Cursor::Auto => "auto".to_owned(),
-            &Cursor::Pointer => "pointer".to_owned(),
-            &Cursor::Help => "help".to_owned(),
-            &Cursor::Wait => "wait".to_owned(),
-            &Cursor::Crosshair => "crosshair".to_owned(),
-            &Cursor::NotAllowed => "not-allowed".to_owned(),
-            &Cursor::ZoomIn => "zoom-in".to_owned(),
-            &Cursor::ZoomOut => "zoom-out".to_owned(),
-            &Cursor::Grab => "grab".to_owned(),
+        match self {
+            Cursor::Unset => "unset".to_owned(),
+            Cursor::Auto => "auto".to_owned(),
+            Cursor::Pointer => "pointer".to_owned(),
+            Cursor::Help => "help".to_owned(),
+            Cursor::Wait => "wait".to_owned(),
+            Cursor::Crosshair => "crosshair".to_owned(),
+            Cursor::NotAllowed => "not-allowed".to_owned(),
+            Cursor::ZoomIn => "zoom-in".to_owned(),
+            Cursor::ZoomOut => "zoom-out".to_owned(),
+            Cursor::Grab => "grab".to_owned(),
         }
     }
 }
@@ -155,13 +257,13 @@ pub enum WhiteSpace {
 }
 impl WhiteSpace {
     pub fn get_name(&self) -> String {
-        match &self {
-            &WhiteSpace::Normal => "normal".to_owned(),
-            &WhiteSpace::Nowrap => "nowrap".to_owned(),
-            &WhiteSpace::Pre => "pre".to_owned(),
-            &WhiteSpace::PreWrap => "pre-wrap".to_owned(),
-            &WhiteSpace::PreLine => "pre-line".to_owned(),
-            &WhiteSpace::BreakSpaces => "break-spaces".to_owned(),
+        match self {
+            WhiteSpace::Normal => "normal".to_owned(),
+            WhiteSpace::Nowrap => "nowrap".to_owned(),
+            WhiteSpace::Pre => "pre".to_owned(),
+            WhiteSpace::PreWrap => "pre-wrap".to_owned(),
+            WhiteSpace::PreLine => "pre-line".to_owned(),
+            WhiteSpace::BreakSpaces => "break-spaces".to_owned(),
         }
     }
 }
@@ -179,15 +281,15 @@ pub enum Display {
 }
 impl Display {
     pub fn get_name(&self) -> String {
-        match &self {
-            &Display::Block => "block".to_owned(),
-            &Display::Inline => "inline".to_owned(),
-            &Display::InlineBlock => "inline-block".to_owned(),
-            &Display::Grid => "grid".to_owned(),
-            &Display::InlineGrid => "inline-grid".to_owned(),
-            &Display::FlowRoot => "flow-root".to_owned(),
-            &Display::None => "none".to_owned(),
-            &Display::Contents => "contents".to_owned(),
+        match self {
+            Display::Block => "block".to_owned(),
+            Display::Inline => "inline".to_owned(),
+            Display::InlineBlock => "inline-block".to_owned(),
+            Display::Grid => "grid".to_owned(),
+            Display::InlineGrid => "inline-grid".to_owned(),
+            Display::FlowRoot => "flow-root".to_owned(),
+            Display::None => "none".to_owned(),
+            Display::Contents => "contents".to_owned(),
         }
     }
 }
@@ -203,13 +305,13 @@ pub enum Position {
 }
 impl Position {
     pub fn get_name(&self) -> String {
-        match &self {
-            &Position::Absolute => "absolute".to_owned(),
-            &Position::Fixed => "fixed".to_owned(),
-            &Position::Relative => "relative".to_owned(),
-            &Position::Static => "static".to_owned(),
-            &Position::Sticky => "sticky".to_owned(),
-            &Position::Inherit => "inherit".to_owned(),
+        match self {
+            Position::Absolute => "absolute".to_owned(),
+            Position::Fixed => "fixed".to_owned(),
+            Position::Relative => "relative".to_owned(),
+            Position::Static => "static".to_owned(),
+            Position::Sticky => "sticky".to_owned(),
+            Position::Inherit => "inherit".to_owned(),
         }
     }
 }
@@ -223,11 +325,11 @@ pub enum FontStyle {
 }
 impl FontStyle {
     pub fn get_name(&self) -> String {
-        match &self {
-            &FontStyle::Normal => "normal".to_owned(),
-            &FontStyle::Italic => "italic".to_owned(),
-            &FontStyle::Oblique => "oblique".to_owned(),
-            &FontStyle::Inherit => "inherit".to_owned(),
+        match self {
+            FontStyle::Normal => "normal".to_owned(),
+            FontStyle::Italic => "italic".to_owned(),
+            FontStyle::Oblique => "oblique".to_owned(),
+            FontStyle::Inherit => "inherit".to_owned(),
         }
     }
 }
@@ -252,22 +354,22 @@ pub enum FontWeight {
 }
 impl FontWeight {
     pub fn get_name(&self) -> String {
-        match &self {
-            &FontWeight::Normal => "normal".to_owned(),
-            &FontWeight::Bold => "bold".to_owned(),
-            &FontWeight::Bolder => "bolder".to_owned(),
-            &FontWeight::Lighter => "lighter".to_owned(),
-            &FontWeight::Initial => "initial".to_owned(),
-            &FontWeight::Inherit => "inherit".to_owned(),
-            &FontWeight::Num900 => "900".to_owned(),
-            &FontWeight::Num800 => "800".to_owned(),
-            &FontWeight::Num700 => "700".to_owned(),
-            &FontWeight::Num600 => "600".to_owned(),
-            &FontWeight::Num500 => "500".to_owned(),
-            &FontWeight::Num400 => "400".to_owned(),
-            &FontWeight::Num300 => "300".to_owned(),
-            &FontWeight::Num200 => "200".to_owned(),
-            &FontWeight::Num100 => "100".to_owned(),
+        match self {
+            FontWeight::Normal => "normal".to_owned(),
+            FontWeight::Bold => "bold".to_owned(),
+            FontWeight::Bolder => "bolder".to_owned(),
+            FontWeight::Lighter => "lighter".to_owned(),
+            FontWeight::Initial => "initial".to_owned(),
+            FontWeight::Inherit => "inherit".to_owned(),
+            FontWeight::Num900 => "900".to_owned(),
+            FontWeight::Num800 => "800".to_owned(),
+            FontWeight::Num700 => "700".to_owned(),
+            FontWeight::Num600 => "600".to_owned(),
+            FontWeight::Num500 => "500".to_owned(),
+            FontWeight::Num400 => "400".to_owned(),
+            FontWeight::Num300 => "300".to_owned(),
+            FontWeight::Num200 => "200".to_owned(),
+            FontWeight::Num100 => "100".to_owned(),
         }
     }
 }
@@ -283,13 +385,13 @@ pub enum TextAlign {
 }
 impl TextAlign {
     pub fn get_name(&self) -> String {
-        match &self {
-            &TextAlign::Left => "left".to_owned(),
-            &TextAlign::Right => "right".to_owned(),
-            &TextAlign::Center => "center".to_owned(),
-            &TextAlign::Start => "start".to_owned(),
-            &TextAlign::End => "end".to_owned(),
-            &TextAlign::Justify => "justify".to_owned(),
+        match self {
+            TextAlign::Left => "left".to_owned(),
+            TextAlign::Right => "right".to_owned(),
+            TextAlign::Center => "center".to_owned(),
+            TextAlign::Start => "start".to_owned(),
+            TextAlign::End => "end".to_owned(),
+            TextAlign::Justify => "justify".to_owned(),
         }
     }
 }
@@ -303,11 +405,11 @@ pub enum WordBreak {
 }
 impl WordBreak {
     pub fn get_name(&self) -> String {
-        match &self {
-            &WordBreak::BreakAll => "break-all".to_owned(),
-            &WordBreak::Normal => "normal".to_owned(),
-            &WordBreak::KeepAll => "keep-all".to_owned(),
-            &WordBreak::BreakWord => "break-word".to_owned(),
+        match self {
+            WordBreak::BreakAll => "break-all".to_owned(),
+            WordBreak::Normal => "normal".to_owned(),
+            WordBreak::KeepAll => "keep-all".to_owned(),
+            WordBreak::BreakWord => "break-word".to_owned(),
         }
     }
 }
@@ -319,9 +421,45 @@ pub enum PointerEvents {
 }
 impl PointerEvents {
     pub fn get_name(&self) -> String {
-        match &self {
-            &PointerEvents::Auto => "auto".to_owned(),
-            &PointerEvents::None => "none".to_owned(),
+        match self {
+            PointerEvents::Auto => "auto".to_owned(),
+            PointerEvents::None => "none".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum ContainerType {
+    Normal,
+    Size,
+    InlineSize,
+}
+impl ContainerType {
+    pub fn get_name(&self) -> String {
+        match self {
+            ContainerType::Normal => "normal".to_owned(),
+            ContainerType::Size => "size".to_owned(),
+            ContainerType::InlineSize => "inline-size".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum PullStatus {
+    Normal,
+    Pulling,
+    Loosing,
+    Loading,
+    Success,
+}
+impl PullStatus {
+    pub fn get_name(&self) -> String {
+        match self {
+            PullStatus::Normal => "".to_owned(),
+            PullStatus::Pulling => "下拉即可刷新".to_owned(),
+            PullStatus::Loosing => "释放即可刷新".to_owned(),
+            PullStatus::Loading => "加载中...".to_owned(),
+            PullStatus::Success => "刷新成功".to_owned(),
         }
     }
 }
@@ -331,6 +469,44 @@ pub enum SafeType {
     None,
     Top,
     Bottom,
+    Left,
+    Right,
+    Vertical,
+    Horizontal,
+    All,
+}
+impl SafeType {
+    /// 上 右 下 左，是否需要加上安全区域
+    pub fn get_sides(&self) -> [bool; 4] {
+        match self {
+            SafeType::None => [false, false, false, false],
+            SafeType::Top => [true, false, false, false],
+            SafeType::Bottom => [false, false, true, false],
+            SafeType::Left => [false, false, false, true],
+            SafeType::Right => [false, true, false, false],
+            SafeType::Vertical => [true, false, true, false],
+            SafeType::Horizontal => [false, true, false, true],
+            SafeType::All => [true, true, true, true],
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum SafeMode {
+    Padding,
+    Margin,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum ShowMode {
+    Css,
+    Unmount,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum DesignUnit {
+    Vw,
+    Rem,
 }
 
 #[derive(Clone, PartialEq)]
@@ -397,66 +573,66 @@ pub enum FlexWay {
 }
 impl FlexWay {
     pub fn get_name(&self) -> String {
-        match &self {
-            &FlexWay::Fraa => "fraa".to_owned(),
-            &FlexWay::Frab => "frab".to_owned(),
-            &FlexWay::Frac => "frac".to_owned(),
-            &FlexWay::Frae => "frae".to_owned(),
-            &FlexWay::Fras => "fras".to_owned(),
-
-            &FlexWay::Frba => "frba".to_owned(),
-            &FlexWay::Frbb => "frbb".to_owned(),
-            &FlexWay::Frbc => "frbc".to_owned(),
-            &FlexWay::Frbe => "frbe".to_owned(),
-            &FlexWay::Frbs => "frbs".to_owned(),
-
-            &FlexWay::Frca => "frca".to_owned(),
-            &FlexWay::Frcb => "frcb".to_owned(),
-            &FlexWay::Frcc => "frcc".to_owned(),
-            &FlexWay::Frce => "frce".to_owned(),
-            &FlexWay::Frcs => "frcs".to_owned(),
-
-            &FlexWay::Frea => "frea".to_owned(),
-            &FlexWay::Freb => "freb".to_owned(),
-            &FlexWay::Frec => "frec".to_owned(),
-            &FlexWay::Free => "free".to_owned(),
-            &FlexWay::Fres => "fres".to_owned(),
-
-            &FlexWay::Frsa => "frsa".to_owned(),
-            &FlexWay::Frsb => "frsb".to_owned(),
-            &FlexWay::Frsc => "frsc".to_owned(),
-            &FlexWay::Frse => "frse".to_owned(),
-            &FlexWay::Frss => "frss".to_owned(),
-
-            &FlexWay::Fcaa => "fcaa".to_owned(),
-            &FlexWay::Fcab => "fcab".to_owned(),
-            &FlexWay::Fcac => "fcac".to_owned(),
-            &FlexWay::Fcae => "fcae".to_owned(),
-            &FlexWay::Fcas => "fcas".to_owned(),
-
-            &FlexWay::Fcba => "fcba".to_owned(),
-            &FlexWay::Fcbb => "fcbb".to_owned(),
-            &FlexWay::Fcbc => "fcbc".to_owned(),
-            &FlexWay::Fcbe => "fcbe".to_owned(),
-            &FlexWay::Fcbs => "fcbs".to_owned(),
-
-            &FlexWay::Fcca => "fcca".to_owned(),
-            &FlexWay::Fccb => "fccb".to_owned(),
-            &FlexWay::Fccc => "fccc".to_owned(),
-            &FlexWay::Fcce => "fcce".to_owned(),
-            &FlexWay::Fccs => "fccs".to_owned(),
-
-            &FlexWay::Fcea => "fcea".to_owned(),
-            &FlexWay::Fceb => "fceb".to_owned(),
-            &FlexWay::Fcec => "fcec".to_owned(),
-            &FlexWay::Fcee => "fcee".to_owned(),
-            &FlexWay::Fces => "fces".to_owned(),
-
-            &FlexWay::Fcsa => "fcsa".to_owned(),
-            &FlexWay::Fcsb => "fcsb".to_owned(),
-            &FlexWay::Fcsc => "fcsc".to_owned(),
-            &FlexWay::Fcse => "fcse".to_owned(),
-            &FlexWay::Fcss => "fcss".to_owned(),
+        match self {
+            FlexWay::Fraa => "fraa".to_owned(),
+            FlexWay::Frab => "frab".to_owned(),
+            FlexWay::Frac => "frac".to_owned(),
+            FlexWay::Frae => "frae".to_owned(),
+            FlexWay::Fras => "fras".to_owned(),
+
+            FlexWay::Frba => "frba".to_owned(),
+            FlexWay::Frbb => "frbb".to_owned(),
+            FlexWay::Frbc => "frbc".to_owned(),
+            FlexWay::Frbe => "frbe".to_owned(),
+            FlexWay::Frbs => "frbs".to_owned(),
+
+            FlexWay::Frca => "frca".to_owned(),
+            FlexWay::Frcb => "frcb".to_owned(),
+            FlexWay::Frcc => "frcc".to_owned(),
+            FlexWay::Frce => "frce".to_owned(),
+            FlexWay::Frcs => "frcs".to_owned(),
+
+            FlexWay::Frea => "frea".to_owned(),
+            FlexWay::Freb => "freb".to_owned(),
+            FlexWay::Frec => "frec".to_owned(),
+            FlexWay::Free => "free".to_owned(),
+            FlexWay::Fres => "fres".to_owned(),
+
+            FlexWay::Frsa => "frsa".to_owned(),
+            FlexWay::Frsb => "frsb".to_owned(),
+            FlexWay::Frsc => "frsc".to_owned(),
+            FlexWay::Frse => "frse".to_owned(),
+            FlexWay::Frss => "frss".to_owned(),
+
+            FlexWay::Fcaa => "fcaa".to_owned(),
+            FlexWay::Fcab => "fcab".to_owned(),
+            FlexWay::Fcac => "fcac".to_owned(),
+            FlexWay::Fcae => "fcae".to_owned(),
+            FlexWay::Fcas => "fcas".to_owned(),
+
+            FlexWay::Fcba => "fcba".to_owned(),
+            FlexWay::Fcbb => "fcbb".to_owned(),
+            FlexWay::Fcbc => "fcbc".to_owned(),
+            FlexWay::Fcbe => "fcbe".to_owned(),
+            FlexWay::Fcbs => "fcbs".to_owned(),
+
+            FlexWay::Fcca => "fcca".to_owned(),
+            FlexWay::Fccb => "fccb".to_owned(),
+            FlexWay::Fccc => "fccc".to_owned(),
+            FlexWay::Fcce => "fcce".to_owned(),
+            FlexWay::Fccs => "fccs".to_owned(),
+
+            FlexWay::Fcea => "fcea".to_owned(),
+            FlexWay::Fceb => "fceb".to_owned(),
+            FlexWay::Fcec => "fcec".to_owned(),
+            FlexWay::Fcee => "fcee".to_owned(),
+            FlexWay::Fces => "fces".to_owned(),
+
+            FlexWay::Fcsa => "fcsa".to_owned(),
+            FlexWay::Fcsb => "fcsb".to_owned(),
+            FlexWay::Fcsc => "fcsc".to_owned(),
+            FlexWay::Fcse => "fcse".to_owned(),
+            FlexWay::Fcss => "fcss".to_owned(),
         }
     }
 }
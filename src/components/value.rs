@@ -0,0 +1,304 @@
+use std::fmt;
+
+use yew::html::IntoPropValue;
+
+use crate::utils::{report, split_values};
+
+/// 宽 高，"100% 50" "md:50%"，数字为 px
+/// ### 使用示例
+///```text
+/// size="100 50"
+/// size={100}
+/// size={(100, 50)}
+/// size={("100%", "50vh")}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Size(Option<String>);
+
+/// 上 右 下 左，用于 padding margin radius border_width，数字为 px
+/// ### 使用示例
+///```text
+/// padding="10 20"
+/// padding={10}
+/// padding={(10, 20)}
+/// padding={(10, 20, 10, 20)}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Spacing(Option<String>);
+
+/// 圆角，同 Spacing，可用 / 分隔水平和垂直半径，数字为 px
+/// ### 使用示例
+///```text
+/// radius="10"
+/// radius="10 20"
+/// radius="10/20"
+/// radius={(10, 20)}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Radius(Option<String>);
+
+/// 不透明度，数字会限制在 0 ~ 1
+/// ### 使用示例
+///```text
+/// opacity="0.5 md:1"
+/// opacity={0.5}
+/// opacity={1}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Opacity(Option<String>);
+
+/// transition 时间，数字为 s，字符串可带 s 或 ms
+/// ### 使用示例
+///```text
+/// duration="0.3"
+/// duration="300ms"
+/// duration={0.3}
+/// duration={std::time::Duration::from_millis(300)}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Duration(Option<String>);
+
+/// 层级
+/// ### 使用示例
+///```text
+/// z_index="10"
+/// z_index={10}
+///```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ZIndex(Option<String>);
+
+/// 组件中的样式属性，未设置时 get 为 None
+/// 字符串和枚举的属性为 Option，Size 等类型的默认值为未设置
+pub trait StyleProp {
+    type Value;
+    fn get(&self) -> Option<&Self::Value>;
+}
+
+impl<T> StyleProp for Option<T> {
+    type Value = T;
+    fn get(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+/// 实现 From 和 IntoPropValue，$f 将值转为属性的字符串，debug 模式下用 $check 检查每个值
+/// Default 为未设置，用于组件中的样式属性
+macro_rules! impl_prop_value {
+    ($ty:ident, check = $check:expr, $($from:ty => $f:expr),* $(,)?) => {
+        impl StyleProp for $ty {
+            type Value = $ty;
+            fn get(&self) -> Option<&$ty> {
+                self.0.as_ref().map(|_| self)
+            }
+        }
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0.as_deref().unwrap_or_default())
+            }
+        }
+        $(
+            impl From<$from> for $ty {
+                fn from(value: $from) -> Self {
+                    let f: fn($from) -> String = $f;
+                    let data = f(value);
+                    if cfg!(debug_assertions) {
+                        let invalid = invalid_values(&data, $check);
+                        if !invalid.is_empty() {
+                            report(&format!(
+                                "yew_quick {}: invalid values {:?} in {:?}",
+                                stringify!($ty),
+                                invalid,
+                                data
+                            ));
+                        }
+                    }
+                    $ty(Some(data))
+                }
+            }
+            impl IntoPropValue<$ty> for $from {
+                fn into_prop_value(self) -> $ty {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_prop_value!(
+    Size,
+    check = is_size,
+    &str => |v| v.to_owned(),
+    String => |v| v,
+    i32 => |v| v.to_string(),
+    f64 => |v| v.to_string(),
+    (i32, i32) => |(w, h)| format!("{} {}", w, h),
+    (f64, f64) => |(w, h)| format!("{} {}", w, h),
+    (&str, &str) => |(w, h)| format!("{} {}", w, h),
+);
+
+impl_prop_value!(
+    Spacing,
+    check = is_size,
+    &str => |v| v.to_owned(),
+    String => |v| v,
+    i32 => |v| v.to_string(),
+    f64 => |v| v.to_string(),
+    (i32, i32) => |(a, b)| format!("{} {}", a, b),
+    (f64, f64) => |(a, b)| format!("{} {}", a, b),
+    (&str, &str) => |(a, b)| format!("{} {}", a, b),
+    (i32, i32, i32, i32) => |(a, b, c, d)| format!("{} {} {} {}", a, b, c, d),
+    (f64, f64, f64, f64) => |(a, b, c, d)| format!("{} {} {} {}", a, b, c, d),
+);
+
+impl_prop_value!(
+    Radius,
+    check = is_radius,
+    &str => |v| v.to_owned(),
+    String => |v| v,
+    i32 => |v| v.to_string(),
+    f64 => |v| v.to_string(),
+    (i32, i32) => |(a, b)| format!("{} {}", a, b),
+    (f64, f64) => |(a, b)| format!("{} {}", a, b),
+    (&str, &str) => |(a, b)| format!("{} {}", a, b),
+    (i32, i32, i32, i32) => |(a, b, c, d)| format!("{} {} {} {}", a, b, c, d),
+    (f64, f64, f64, f64) => |(a, b, c, d)| format!("{} {} {} {}", a, b, c, d),
+);
+
+impl_prop_value!(
+    Opacity,
+    check = is_opacity,
+    &str => |v| v.to_owned(),
+    String => |v| v,
+    i32 => |v| v.clamp(0, 1).to_string(),
+    f64 => |v| v.clamp(0.0, 1.0).to_string(),
+);
+
+impl_prop_value!(
+    Duration,
+    check = is_duration,
+    &str => to_seconds,
+    String => |v| to_seconds(&v),
+    i32 => |v| v.to_string(),
+    f64 => |v| v.to_string(),
+    std::time::Duration => |v| v.as_secs_f64().to_string(),
+);
+
+impl_prop_value!(
+    ZIndex,
+    check = is_z_index,
+    &str => |v| v.to_owned(),
+    String => |v| v,
+    i32 => |v| v.to_string(),
+);
+
+/// "300ms md:0.5s" => "0.3 md:0.5"
+fn to_seconds(data: &str) -> String {
+    let seconds = |value: &str| match value.strip_suffix("ms") {
+        Some(ms) => match ms.parse::<f64>() {
+            Ok(ms) => (ms / 1000.0).to_string(),
+            Err(_) => value.to_owned(),
+        },
+        None => value.strip_suffix("s").unwrap_or(value).to_owned(),
+    };
+    split_values(data)
+        .iter()
+        .map(|x| match x.split_once(":") {
+            Some((name, value)) => format!("{}:{}", name, seconds(value)),
+            None => seconds(x),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// 去掉断点前缀后无效的值，"0.5 md:O.5" => ["O.5"]
+fn invalid_values(data: &str, is_valid: fn(&str) -> bool) -> Vec<String> {
+    split_values(data)
+        .into_iter()
+        .map(|x| match x.split_once(":") {
+            Some((_, value)) if !x.starts_with("var(") => value.to_owned(),
+            _ => x,
+        })
+        .filter(|x| !is_valid(x))
+        .collect()
+}
+
+/// 全局关键词、var() calc() 等函数
+fn is_css_wide(value: &str) -> bool {
+    ["inherit", "initial", "unset", "revert"].contains(&value)
+        || (value.contains("(") && value.ends_with(")"))
+}
+
+fn is_number(value: &str) -> bool {
+    value.parse::<f64>().map(|x| x.is_finite()).unwrap_or(false)
+}
+
+/// 数字、带单位的长度或关键词，如 "100" "50%" "10rpx" "auto"
+fn is_size(value: &str) -> bool {
+    const UNITS: [&str; 28] = [
+        "px", "rpx", "%", "em", "rem", "vw", "vh", "vmin", "vmax", "dvw", "dvh", "svw", "svh",
+        "lvw", "lvh", "pt", "pc", "cm", "mm", "in", "q", "ch", "ex", "cqw", "cqh", "cqi", "cqb",
+        "fr",
+    ];
+    if is_css_wide(value) {
+        return true;
+    }
+    let unit_at = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    if unit_at == 0 {
+        // 关键词，如 auto fit-content
+        return value.chars().all(|c| c.is_ascii_lowercase() || c == '-');
+    }
+    is_number(&value[..unit_at]) && (unit_at == value.len() || UNITS.contains(&&value[unit_at..]))
+}
+
+/// 长度，或用 / 分隔的水平和垂直半径，如 "10/20" "10% / 20%"
+fn is_radius(value: &str) -> bool {
+    is_size(value) || value.split("/").all(|x| x.is_empty() || is_size(x))
+}
+
+fn is_opacity(value: &str) -> bool {
+    is_css_wide(value)
+        || is_number(value)
+        || value.strip_suffix("%").map(is_number).unwrap_or(false)
+}
+
+/// to_seconds 之后的秒数
+fn is_duration(value: &str) -> bool {
+    value.parse::<f64>().map(|x| x >= 0.0).unwrap_or(false)
+}
+
+fn is_z_index(value: &str) -> bool {
+    is_css_wide(value) || value == "auto" || value.parse::<i32>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(invalid_values("O.5", is_opacity), vec!["O.5"]);
+        assert_eq!(invalid_values("0.5 md:O.5", is_opacity), vec!["O.5"]);
+        assert!(invalid_values("0.5 md:1 lg:inherit", is_opacity).is_empty());
+        assert!(invalid_values("100 md:50% calc(100%_-_10px) auto 750rpx", is_size).is_empty());
+        assert_eq!(invalid_values("100 1OO 10pz", is_size), vec!["1OO", "10pz"]);
+        assert!(invalid_values(&to_seconds("300ms md:0.5s"), is_duration).is_empty());
+        assert_eq!(
+            invalid_values(&to_seconds("fast"), is_duration),
+            vec!["fast"]
+        );
+        assert!(invalid_values("10/20 10% / 20% md:8", is_radius).is_empty());
+        assert_eq!(invalid_values("10/2O", is_radius), vec!["10/2O"]);
+        assert_eq!(invalid_values("10/20", is_size), vec!["10/20"]);
+        assert!(invalid_values("10 md:auto", is_z_index).is_empty());
+        assert_eq!(invalid_values("1.5", is_z_index), vec!["1.5"]);
+    }
+
+    #[test]
+    fn opacity_from_i32() {
+        assert_eq!(Opacity::from(1).to_string(), "1");
+        assert_eq!(Opacity::from(3).to_string(), "1");
+        assert_eq!(Opacity::from(-1).to_string(), "0");
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use wasm_bindgen::JsCast;
+use yew::{
+    function_component, html, use_effect, use_effect_with_deps, use_force_update, use_mut_ref,
+    use_node_ref, use_state, Callback, Event, Html, Properties,
+};
+
+use crate::prelude::Size;
+use crate::utils::{is_have_unit, style_source};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct VirtualListProps {
+    pub item_count: usize,
+    pub render_item: Callback<usize, Html>,
+    #[prop_or_default]
+    pub item_key: Option<Callback<usize, String>>,
+    #[prop_or(Size::from("50"))]
+    pub item_height: Size,
+    #[prop_or(false)]
+    pub variable: bool,
+    #[prop_or(Size::from("100% 400"))]
+    pub size: Size,
+    #[prop_or(5)]
+    pub overscan: usize,
+    #[prop_or_default]
+    pub group_headers: Vec<usize>,
+    #[prop_or_default]
+    pub scroll_to_index: Option<usize>,
+    #[prop_or_default]
+    pub scroll_request: usize,
+    #[prop_or_default]
+    pub onscroll: Callback<f64>,
+}
+
+/// ### 使用示例
+///```text
+/// item_count: usize, // 总行数
+/// render_item: Callback<usize, Html>, // 根据下标渲染每一行
+/// item_key: Option<Callback<usize, String>>, // 每一行的 key，默认为下标
+/// item_height: Size, // 行高 "50"，variable 为 true 时，为预估行高
+/// variable: bool, // 行高不固定，渲染后测量实际高度
+/// size: Size, // 列表的长宽 "100% 400"
+/// overscan: usize, // 可见区域外，上下额外渲染的行数
+/// group_headers: Vec<usize>, // 分组标题所在的下标（升序），滚动时吸顶
+/// scroll_to_index: Option<usize>, // 滚动到指定下标
+/// scroll_request: usize, // 改变后再次滚动到 scroll_to_index，用于重复滚动到同一下标
+/// onscroll: Callback<f64>, // 滚动时的 scrollTop
+///```
+#[function_component]
+pub fn VirtualList(props: &VirtualListProps) -> Html {
+    let node = use_node_ref();
+    let scroll_top = use_state(|| 0.0_f64);
+    let viewport_height = use_state(|| 0.0_f64);
+    let measured = use_mut_ref(HashMap::<usize, f64>::new);
+    let force_update = use_force_update();
+
+    let estimate = parse_height(&props.item_height.to_string()).unwrap_or(50.0);
+
+    // 每一行的起始位置，最后一项为总高度
+    let offsets = {
+        let measured = measured.borrow();
+        let mut offsets = Vec::with_capacity(props.item_count + 1);
+        let mut total = 0.0;
+        offsets.push(total);
+        for i in 0..props.item_count {
+            total += if props.variable {
+                *measured.get(&i).unwrap_or(&estimate)
+            } else {
+                estimate
+            };
+            offsets.push(total);
+        }
+        offsets
+    };
+    let total_height = offsets[props.item_count];
+
+    let find_index = |top: f64| find_index(&offsets, top);
+
+    let first = find_index(*scroll_top);
+    let last = find_index(*scroll_top + *viewport_height);
+    let start = first.saturating_sub(props.overscan);
+    let end = if props.item_count == 0 {
+        0
+    } else {
+        (last + props.overscan + 1).min(props.item_count)
+    };
+
+    {
+        let node = node.clone();
+        let viewport_height = viewport_height.clone();
+        let measured = measured.clone();
+        let variable = props.variable;
+        use_effect(move || {
+            if let Some(el) = node.cast::<web_sys::Element>() {
+                let h = el.client_height() as f64;
+                if h != *viewport_height {
+                    viewport_height.set(h);
+                }
+                if variable {
+                    let mut changed = false;
+                    let rows = el.query_selector_all("[data-index]").unwrap();
+                    for i in 0..rows.length() {
+                        let row = rows
+                            .get(i)
+                            .and_then(|x| x.dyn_into::<web_sys::HtmlElement>().ok());
+                        if let Some(row) = row {
+                            let index = row
+                                .get_attribute("data-index")
+                                .and_then(|x| x.parse::<usize>().ok());
+                            if let Some(index) = index {
+                                let h = row.offset_height() as f64;
+                                let mut measured = measured.borrow_mut();
+                                if measured.get(&index) != Some(&h) {
+                                    measured.insert(index, h);
+                                    changed = true;
+                                }
+                            }
+                        }
+                    }
+                    if changed {
+                        force_update.force_update();
+                    }
+                }
+            }
+            || ()
+        });
+    }
+
+    {
+        let node = node.clone();
+        let offsets = offsets.clone();
+        use_effect_with_deps(
+            move |(index, _)| {
+                if let (Some(index), Some(el)) = (index, node.cast::<web_sys::Element>()) {
+                    if let Some(top) = offsets.get(*index) {
+                        el.set_scroll_top(*top as i32);
+                    }
+                }
+                || ()
+            },
+            (props.scroll_to_index, props.scroll_request),
+        );
+    }
+
+    let onscroll = {
+        let node = node.clone();
+        let scroll_top = scroll_top.clone();
+        let on_scroll = props.onscroll.clone();
+        Callback::from(move |_: Event| {
+            if let Some(el) = node.cast::<web_sys::Element>() {
+                let top = el.scroll_top() as f64;
+                scroll_top.set(top);
+                on_scroll.emit(top);
+            }
+        })
+    };
+
+    let size = props.size.to_string();
+    let temp_size = size.split(" ").collect::<Vec<&str>>();
+    let temp_height = if temp_size.len() == 1 {
+        temp_size[0]
+    } else {
+        temp_size[1]
+    };
+    let size_value = |v: &str| {
+        if v == "auto" || is_have_unit(v) || v.contains("%") {
+            v.to_string()
+        } else {
+            v.to_string() + "px"
+        }
+    };
+
+    let class = style_source(format!(
+        r#"
+            position: relative;
+            width: {width};
+            height: {height};
+            overflow-y: auto;
+            -webkit-overflow-scrolling: touch;
+
+            .virtual-list-sticky {{
+                position: sticky;
+                top: 0;
+                height: 0;
+                z-index: 1;
+                overflow: visible;
+            }}
+            .virtual-list-sticky > div {{
+                background-color: inherit;
+            }}
+            .virtual-list-phantom {{
+                position: relative;
+            }}
+            .virtual-list-row {{
+                position: absolute;
+                left: 0;
+                width: 100%;
+            }}
+        "#,
+        width = size_value(temp_size[0]),
+        height = size_value(temp_height),
+    ));
+
+    let key = |i: usize| match &props.item_key {
+        Some(item_key) => item_key.emit(i),
+        None => i.to_string(),
+    };
+
+    // 当前可见区域所属的分组标题
+    let sticky_header = props
+        .group_headers
+        .iter()
+        .take_while(|x| **x <= first)
+        .last()
+        .filter(|x| offsets[**x] < *scroll_top);
+
+    html! {
+        <div {class} ref={node} {onscroll}>
+            if let Some(header) = sticky_header {
+                <div class="virtual-list-sticky">
+                    <div key={key(*header)}>{ props.render_item.emit(*header) }</div>
+                </div>
+            }
+            <div class="virtual-list-phantom" style={format!("height: {}px;", total_height)}>
+            {
+                for (start..end).map(|i| {
+                    let style = if props.variable {
+                        format!("top: {}px;", offsets[i])
+                    } else {
+                        format!("top: {}px; height: {}px;", offsets[i], estimate)
+                    };
+                    html! {
+                        <div key={key(i)} class="virtual-list-row" data-index={i.to_string()} {style}>
+                            { props.render_item.emit(i) }
+                        </div>
+                    }
+                })
+            }
+            </div>
+        </div>
+    }
+}
+
+/// 行高，非数字、无穷大或负数时为 None
+fn parse_height(data: &str) -> Option<f64> {
+    data.parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite() && *x >= 0.0)
+}
+
+/// top 所在的行，offsets 为每一行的起始位置，最后一项为总高度
+fn find_index(offsets: &[f64], top: f64) -> usize {
+    let count = offsets.len().saturating_sub(1);
+    match offsets.binary_search_by(|x| x.total_cmp(&top)) {
+        Ok(i) => i,
+        Err(i) => i.saturating_sub(1),
+    }
+    .min(count.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_index, parse_height};
+
+    #[test]
+    fn invalid_heights() {
+        assert_eq!(parse_height("40"), Some(40.0));
+        assert_eq!(parse_height("NaN"), None);
+        assert_eq!(parse_height("inf"), None);
+        assert_eq!(parse_height("-10"), None);
+    }
+
+    #[test]
+    fn index_at_top() {
+        let offsets = [0.0, 50.0, 100.0, 150.0];
+        assert_eq!(find_index(&offsets, 0.0), 0);
+        assert_eq!(find_index(&offsets, 60.0), 1);
+        assert_eq!(find_index(&offsets, 100.0), 2);
+        assert_eq!(find_index(&offsets, 500.0), 2);
+        assert_eq!(find_index(&offsets, f64::NAN), 2);
+        assert_eq!(find_index(&[0.0], 10.0), 0);
+    }
+}
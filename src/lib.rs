@@ -1,6 +1,9 @@
 mod components;
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub mod testing;
 mod utils;
 
 pub mod prelude {
     pub use crate::components::*;
+    pub use yew_quick_macro::q;
 }
@@ -0,0 +1,394 @@
+/// 渲染结果，包含 html 和渲染期间生成的样式
+#[derive(Clone, Debug)]
+pub struct Rendered {
+    html: String,
+    elements: Vec<Element>,
+    styles: Vec<(String, Vec<Rule>)>,
+}
+
+/// 渲染结果中的一个元素
+#[derive(Clone, Copy)]
+pub struct Node<'a> {
+    rendered: &'a Rendered,
+    index: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Element {
+    tag: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Child>,
+}
+
+#[derive(Clone, Debug)]
+enum Child {
+    Element(usize),
+    Text(String),
+}
+
+/// css 规则，media 为所在的 @media 条件
+#[derive(Clone, Debug)]
+struct Rule {
+    media: Option<String>,
+    selector: String,
+    declarations: Vec<(String, String)>,
+}
+
+const VOID_TAGS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+impl Rendered {
+    pub(crate) fn new(html: String, styles: Vec<(String, String)>) -> Self {
+        Rendered {
+            elements: parse_html(&html),
+            styles: styles
+                .into_iter()
+                .map(|(class, css)| (class, parse_css(&css, None)))
+                .collect(),
+            html,
+        }
+    }
+
+    pub fn html(&self) -> &str {
+        &self.html
+    }
+
+    /// 渲染期间生成的全部 css
+    pub fn css(&self) -> String {
+        self.styles
+            .iter()
+            .map(|(_, rules)| to_css(rules))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// 所有元素，按在 html 中出现的顺序
+    pub fn nodes(&self) -> Vec<Node<'_>> {
+        (1..self.elements.len())
+            .map(|index| Node {
+                rendered: self,
+                index,
+            })
+            .collect()
+    }
+
+    pub fn find(&self, f: impl Fn(&Node) -> bool) -> Option<Node<'_>> {
+        self.nodes().into_iter().find(|node| f(node))
+    }
+
+    pub fn find_all(&self, f: impl Fn(&Node) -> bool) -> Vec<Node<'_>> {
+        self.nodes().into_iter().filter(|node| f(node)).collect()
+    }
+
+    /// 按 data-testid 查找，组件中使用 testid="..."
+    pub fn find_by_testid(&self, id: &str) -> Option<Node<'_>> {
+        self.find(|node| node.attr("data-testid") == Some(id))
+    }
+
+    /// 同 find_by_testid，找不到时 panic 并输出 html
+    pub fn get_by_testid(&self, id: &str) -> Node<'_> {
+        self.find_by_testid(id).unwrap_or_else(|| {
+            panic!(
+                "yew_quick testing: no node with data-testid {:?} in\n{}",
+                id, self.html
+            )
+        })
+    }
+
+    pub fn find_all_by_tag(&self, tag: &str) -> Vec<Node<'_>> {
+        self.find_all(|node| node.tag() == tag)
+    }
+}
+
+impl<'a> Node<'a> {
+    fn element(&self) -> &'a Element {
+        &self.rendered.elements[self.index]
+    }
+
+    pub fn tag(&self) -> &'a str {
+        &self.element().tag
+    }
+
+    pub fn attr(&self, name: &str) -> Option<&'a str> {
+        self.element()
+            .attrs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn classes(&self) -> Vec<&'a str> {
+        self.attr("class")
+            .map(|class| class.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn children(&self) -> Vec<Node<'a>> {
+        self.element()
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Child::Element(index) => Some(Node {
+                    rendered: self.rendered,
+                    index: *index,
+                }),
+                Child::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// 元素及其子元素中的文字
+    pub fn text(&self) -> String {
+        self.element()
+            .children
+            .iter()
+            .map(|child| match child {
+                Child::Element(index) => Node {
+                    rendered: self.rendered,
+                    index: *index,
+                }
+                .text(),
+                Child::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+
+    /// 元素的 class 对应的 css
+    pub fn css(&self) -> String {
+        self.styles()
+            .iter()
+            .map(|(_, rules)| to_css(rules))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// 默认状态下的声明，"width" => Some("100px")
+    pub fn declaration(&self, name: &str) -> Option<String> {
+        self.find_declaration("", None, name)
+    }
+
+    /// hover 时的声明
+    pub fn hover_declaration(&self, name: &str) -> Option<String> {
+        self.find_declaration(":hover", None, name)
+    }
+
+    /// dark 模式下的声明
+    pub fn dark_declaration(&self, name: &str) -> Option<String> {
+        self.media_declaration("(prefers-color-scheme: dark)", name)
+    }
+
+    /// @media 中的声明，media 如 "(min-width: 768px)"，容器查询为 "@container (min-width: 768px)"
+    pub fn media_declaration(&self, media: &str, name: &str) -> Option<String> {
+        self.find_declaration("", Some(media), name)
+    }
+
+    fn styles(&self) -> Vec<&'a (String, Vec<Rule>)> {
+        let classes = self.classes();
+        self.rendered
+            .styles
+            .iter()
+            .filter(|(class, _)| classes.contains(&class.as_str()))
+            .collect()
+    }
+
+    /// 后出现的声明覆盖先出现的
+    fn find_declaration(&self, pseudo: &str, media: Option<&str>, name: &str) -> Option<String> {
+        let media = media.map(normalize);
+        self.styles()
+            .iter()
+            .flat_map(|(class, rules)| {
+                let selector = format!(".{}{}", class, pseudo);
+                rules
+                    .iter()
+                    .filter(move |rule| rule.selector == selector)
+                    .collect::<Vec<&Rule>>()
+            })
+            .filter(|rule| rule.media == media)
+            .flat_map(|rule| rule.declarations.iter())
+            .rfind(|(n, _)| n == name)
+            .map(|(_, value)| value.clone())
+    }
+}
+
+impl std::fmt::Debug for Node<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Node")
+            .field("tag", &self.tag())
+            .field("attrs", &self.element().attrs)
+            .finish()
+    }
+}
+
+/// 解析 SSR 输出的 html，下标 0 为根节点
+fn parse_html(html: &str) -> Vec<Element> {
+    let mut elements = vec![Element::default()];
+    let mut stack = vec![0];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let parent = *stack.last().unwrap_or(&0);
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map(|(_, r)| r).unwrap_or("");
+        } else if let Some(end) = rest.strip_prefix("</") {
+            let (tag, r) = end.split_once('>').unwrap_or((end, ""));
+            let tag = tag.trim().to_lowercase();
+            if let Some(i) = stack.iter().rposition(|i| elements[*i].tag == tag) {
+                stack.truncate(i);
+            }
+            rest = r;
+        } else if rest.starts_with('<') {
+            let (tag, attrs, self_closing, r) = parse_tag(&rest[1..]);
+            let index = elements.len();
+            elements.push(Element {
+                tag: tag.clone(),
+                attrs,
+                children: vec![],
+            });
+            elements[parent].children.push(Child::Element(index));
+            if !self_closing && !VOID_TAGS.contains(&tag.as_str()) {
+                stack.push(index);
+            }
+            rest = r;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            elements[parent]
+                .children
+                .push(Child::Text(unescape(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+    elements
+}
+
+/// 解析 < 之后的开始标签，返回 (标签名, 属性, 是否自闭合, 剩余部分)
+fn parse_tag(data: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let end = data
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(data.len());
+    let tag = data[..end].to_lowercase();
+    let mut rest = &data[end..];
+    let mut attrs = vec![];
+    loop {
+        rest = rest.trim_start();
+        if let Some(r) = rest.strip_prefix("/>") {
+            return (tag, attrs, true, r);
+        }
+        if let Some(r) = rest.strip_prefix('>') {
+            return (tag, attrs, false, r);
+        }
+        if rest.is_empty() {
+            return (tag, attrs, false, rest);
+        }
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len())
+            .max(1);
+        let name = rest[..end].to_lowercase();
+        rest = rest[end..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(r) => {
+                let r = r.trim_start();
+                let (value, r) = match r.chars().next() {
+                    Some(quote) if quote == '"' || quote == '\'' => {
+                        r[1..].split_once(quote).unwrap_or((&r[1..], ""))
+                    }
+                    _ => {
+                        let end = r
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(r.len());
+                        (&r[..end], &r[end..])
+                    }
+                };
+                rest = r;
+                unescape(value)
+            }
+            None => String::new(),
+        };
+        attrs.push((name, value));
+    }
+}
+
+fn unescape(data: &str) -> String {
+    data.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// 解析 stylist 生成的 css，@media 中的规则记录所在的条件
+fn parse_css(css: &str, media: Option<String>) -> Vec<Rule> {
+    let mut rules = vec![];
+    let mut rest = css;
+    while let Some(open) = rest.find('{') {
+        let prelude = rest[..open].trim();
+        let body_start = open + 1;
+        let mut depth = 1;
+        let mut close = rest.len();
+        for (i, c) in rest[body_start..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = body_start + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let body = &rest[body_start..close];
+        if let Some(query) = prelude.strip_prefix("@media") {
+            rules.extend(parse_css(body, Some(normalize(query))));
+        } else if prelude.starts_with("@container") {
+            rules.extend(parse_css(body, Some(normalize(prelude))));
+        } else {
+            rules.push(Rule {
+                media: media.clone(),
+                selector: prelude.to_owned(),
+                declarations: body
+                    .split(';')
+                    .filter_map(|x| x.split_once(':'))
+                    .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+                    .collect(),
+            });
+        }
+        rest = rest.get(close + 1..).unwrap_or("");
+    }
+    rules
+}
+
+fn to_css(rules: &[Rule]) -> String {
+    rules
+        .iter()
+        .map(|rule| {
+            let declarations = rule
+                .declarations
+                .iter()
+                .map(|(name, value)| format!("{}: {};\n", name, value))
+                .collect::<String>();
+            let css = format!("{} {{\n{}}}\n", rule.selector, declarations);
+            match &rule.media {
+                Some(media) if media.starts_with("@container") => {
+                    format!("{} {{\n{}}}\n", media, css)
+                }
+                Some(media) => format!("@media {} {{\n{}}}\n", media, css),
+                None => css,
+            }
+        })
+        .collect()
+}
+
+/// 去掉多余的空白，"( min-width:768px )" 与 "(min-width: 768px)" 视为相同
+fn normalize(data: &str) -> String {
+    data.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(": ", ":")
+        .replace(':', ": ")
+}
@@ -0,0 +1,22 @@
+//! 在原生环境（cargo test）中通过 SSR 渲染组件，得到 html 以及每个节点上 stylist 生成的 css
+//! 需要开启 testing 特性，只记录通过 style_source 创建的样式
+//!
+//! ```
+//! # use yew::html;
+//! # use yew_quick::prelude::*;
+//! # use yew_quick::testing::render_html;
+//! let page = render_html(html! {
+//!     <Box testid="card" size="100 md:200" h_bg_color="#eee">{"hi"}</Box>
+//! });
+//! let card = page.get_by_testid("card");
+//! assert_eq!(card.text(), "hi");
+//! assert_eq!(card.declaration("width").as_deref(), Some("100px"));
+//! assert_eq!(card.media_declaration("(min-width: 768px)", "width").as_deref(), Some("200px"));
+//! assert_eq!(card.hover_declaration("background-color").as_deref(), Some("#eee"));
+//! ```
+
+mod dom;
+mod render;
+
+pub use dom::*;
+pub use render::*;
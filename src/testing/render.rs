@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+
+use stylist::{Style, StyleSource};
+use yew::{function_component, BaseComponent, Html, LocalServerRenderer, Properties};
+
+use super::Rendered;
+
+thread_local! {
+    /// 渲染期间生成的样式 (class, css)，不在渲染中时为 None
+    static STYLES: RefCell<Option<Vec<(String, String)>>> = const { RefCell::new(None) };
+}
+
+/// 记录 style_source 生成的样式，只在 testing 渲染期间生效
+pub(crate) fn record_style(source: &StyleSource) {
+    STYLES.with(|styles| {
+        if let Some(styles) = styles.borrow_mut().as_mut() {
+            // 与组件 class 使用同一个默认 StyleManager，已注册的样式返回相同的 class
+            if let Ok(style) = Style::new(source.clone()) {
+                let class = style.get_class_name().to_owned();
+                if styles.iter().all(|(c, _)| c != &class) {
+                    styles.push((class, style.get_style_str().to_owned()));
+                }
+            }
+        }
+    });
+}
+
+/// 渲染属性为默认值的组件
+pub fn render<COMP>() -> Rendered
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    render_with_props::<COMP>(COMP::Properties::default())
+}
+
+/// 使用指定属性渲染组件
+pub fn render_with_props<COMP>(props: COMP::Properties) -> Rendered
+where
+    COMP: BaseComponent,
+{
+    STYLES.with(|styles| *styles.borrow_mut() = Some(vec![]));
+    let html = futures::executor::block_on(
+        LocalServerRenderer::<COMP>::with_props(props)
+            .hydratable(false)
+            .render(),
+    );
+    let styles = STYLES.with(|styles| styles.borrow_mut().take().unwrap_or_default());
+    Rendered::new(html, styles)
+}
+
+/// 直接渲染 html! 片段
+pub fn render_html(html: Html) -> Rendered {
+    render_with_props::<Root>(RootProps { html })
+}
+
+#[derive(Properties, PartialEq)]
+struct RootProps {
+    html: Html,
+}
+
+#[function_component]
+fn Root(props: &RootProps) -> Html {
+    props.html.clone()
+}
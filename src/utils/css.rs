@@ -0,0 +1,201 @@
+use stylist::StyleSource;
+
+use crate::prelude::Breakpoints;
+use crate::utils::split_values;
+
+/// 组件生成的样式，分为默认、hover、dark 模式三部分
+#[derive(Clone, PartialEq, Default)]
+pub struct CssBlocks {
+    pub base: Vec<(&'static str, String)>,
+    pub hover: Vec<(&'static str, String)>,
+    pub dark: Vec<(&'static str, String)>,
+}
+
+impl CssBlocks {
+    pub fn to_css(&self) -> String {
+        let mut css = declarations(&self.base);
+        if !self.hover.is_empty() {
+            css += &format!("&:hover {{\n{}}}\n", declarations(&self.hover));
+        }
+        if !self.dark.is_empty() {
+            css += &format!(
+                "@media (prefers-color-scheme: dark) {{\n& {{\n{}}}\n}}\n",
+                declarations(&self.dark)
+            );
+        }
+        css
+    }
+
+    /// 只保留与 other 不同的值，用于生成响应式的 @media
+    pub fn diff(&self, other: &CssBlocks) -> CssBlocks {
+        let diff = |a: &Vec<(&'static str, String)>, b: &Vec<(&'static str, String)>| {
+            a.iter()
+                .enumerate()
+                .filter(|(i, x)| b.get(*i) != Some(*x))
+                .map(|(_, x)| x.clone())
+                .collect::<Vec<(&'static str, String)>>()
+        };
+        CssBlocks {
+            base: diff(&self.base, &other.base),
+            hover: diff(&self.hover, &other.hover),
+            dark: diff(&self.dark, &other.dark),
+        }
+    }
+
+    /// 包裹在 @media 或 @container 中，query 如 "@media (min-width: 768px)"
+    pub fn to_media_css(&self, query: &str) -> String {
+        let mut css = String::new();
+        if !self.base.is_empty() || !self.hover.is_empty() {
+            css += &format!("{} {{\n", query);
+            if !self.base.is_empty() {
+                css += &format!("& {{\n{}}}\n", declarations(&self.base));
+            }
+            if !self.hover.is_empty() {
+                css += &format!("&:hover {{\n{}}}\n", declarations(&self.hover));
+            }
+            css += "}\n";
+        }
+        if !self.dark.is_empty() {
+            css += &format!(
+                "{} {{\n@media (prefers-color-scheme: dark) {{\n& {{\n{}}}\n}}\n}}\n",
+                query,
+                declarations(&self.dark)
+            );
+        }
+        css
+    }
+}
+
+/// 相邻且相同的声明只保留一个，值为空的声明不输出
+fn declarations(list: &[(&'static str, String)]) -> String {
+    list.iter()
+        .enumerate()
+        .filter(|(i, x)| *i == 0 || list[i - 1] != **x)
+        .filter(|(_, (_, v))| !v.is_empty())
+        .map(|(_, (k, v))| format!("{}: {};\n", k, v))
+        .collect::<String>()
+}
+
+/// 由运行时生成的 css 字符串创建样式
+#[track_caller]
+pub fn style_source(css: String) -> StyleSource {
+    let source = StyleSource::try_from(css).expect("Failed to parse style");
+    #[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+    crate::testing::record_style(&source);
+    source
+}
+
+/// 默认样式，加上各断点下变化的样式
+/// 屏幕断点与上一个屏幕断点比较，"100 md:200 lg:100" 在 lg 下会写回 100，容器断点与默认样式比较
+pub fn responsive_css(
+    base: &CssBlocks,
+    points: Vec<(String, CssBlocks)>,
+    breakpoints: &Breakpoints,
+) -> String {
+    let mut css = base.to_css();
+    let mut prev = base.clone();
+    for (name, blocks) in points {
+        if let Some(query) = breakpoints.get_query(&name) {
+            if name.starts_with("@") {
+                css += &blocks.diff(base).to_media_css(&query);
+            } else {
+                css += &blocks.diff(&prev).to_media_css(&query);
+                prev = blocks;
+            }
+        }
+    }
+    css
+}
+
+/// 拆分响应式的值，is_point 判断是否为断点名称
+/// "100% md:50% lg:33%" => ("100%", [("md", "50%"), ("lg", "33%")])
+/// "10 20 md:5 6" => ("10 20", [("md", "5 6")])
+/// "100% @md/card:50%" => ("100%", [("@md/card", "50%")])
+pub fn split_responsive(
+    data: &str,
+    is_point: &dyn Fn(&str) -> bool,
+) -> (String, Vec<(String, String)>) {
+    let mut base: Vec<String> = vec![];
+    let mut points: Vec<(String, Vec<String>)> = vec![];
+    for x in split_values(data) {
+        let point = x.split_once(":").filter(|(name, _)| is_point(name));
+        match point {
+            Some((name, value)) => points.push((name.to_owned(), vec![value.to_owned()])),
+            None => match points.last_mut() {
+                Some(last) => last.1.push(x),
+                None => base.push(x),
+            },
+        }
+    }
+    (
+        base.join(" "),
+        points
+            .into_iter()
+            .map(|(name, v)| (name, v.join(" ")))
+            .collect(),
+    )
+}
+
+/// 与 props 同类型的空断点列表，供 responsive_props! 推断类型
+pub fn empty_points<T>(_: &T) -> Vec<(String, T)> {
+    vec![]
+}
+
+/// 按断点拆分 css props 中的字符串属性
+/// 枚举类型的属性（display position 等）只有一个值，不能按断点拆分
+/// 返回 (默认值的 props, [(断点名称, 该断点下的 props)])，断点按 Breakpoints::get_order 排序
+macro_rules! responsive_props {
+    ($p:expr, $breakpoints:expr, $($field:ident),* $(,)?) => {{
+        let is_point = |name: &str| $breakpoints.get_query(name).is_some();
+        let mut base = $p.clone();
+        $(
+            base.$field = crate::utils::split_responsive(&$p.$field, &is_point).0;
+        )*
+        let mut points = crate::utils::empty_points(&base);
+        let mut given: Vec<(String, &'static str)> = vec![];
+        $(
+            for (name, v) in crate::utils::split_responsive(&$p.$field, &is_point).1 {
+                given.push((name.clone(), stringify!($field)));
+                match points.iter_mut().find(|x| x.0 == name) {
+                    Some(point) => point.1.$field = v,
+                    None => {
+                        let mut point = base.clone();
+                        point.$field = v;
+                        points.push((name, point));
+                    }
+                }
+            }
+        )*
+        points.sort_by_key(|x| $breakpoints.get_order(&x.0));
+        // 屏幕断点未设置的属性沿用上一个屏幕断点，与 min-width 的层叠一致
+        let mut prev: Option<usize> = None;
+        for i in 0..points.len() {
+            if points[i].0.starts_with("@") {
+                continue;
+            }
+            if let Some(j) = prev {
+                let name = points[i].0.clone();
+                $(
+                    if !given.contains(&(name.clone(), stringify!($field))) {
+                        points[i].1.$field = points[j].1.$field.clone();
+                    }
+                )*
+            }
+            prev = Some(i);
+        }
+        (base, points)
+    }};
+}
+pub(crate) use responsive_props;
+
+/// 按 Design 换算 css props 中的尺寸属性
+macro_rules! design_props {
+    ($p:expr, $design:expr, $($field:ident),* $(,)?) => {{
+        let mut p = $p.clone();
+        $(
+            p.$field = $design.convert(&p.$field);
+        )*
+        p
+    }};
+}
+pub(crate) use design_props;
@@ -1,2 +1,11 @@
-mod utils;
-pub use utils::*;
+mod values;
+pub use values::*;
+
+mod observer;
+pub use observer::*;
+
+mod css;
+pub use css::*;
+
+mod sx;
+pub use sx::*;
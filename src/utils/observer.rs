@@ -0,0 +1,162 @@
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{
+    Element, Event, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit,
+    MediaQueryList, ResizeObserver,
+};
+
+/// IntersectionObserver 的封装，drop 时自动 disconnect
+pub struct IntersectionWatcher {
+    observer: IntersectionObserver,
+    _closure: Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>,
+}
+
+impl IntersectionWatcher {
+    /// root_margin 同 css margin "0px 0px 0px 0px"，threshold 为 0 ~ 1
+    pub fn new<F>(target: &Element, root_margin: &str, threshold: f64, mut callback: F) -> Self
+    where
+        F: FnMut(IntersectionObserverEntry) + 'static,
+    {
+        let closure = Closure::wrap(Box::new(
+            move |entries: js_sys::Array, _: IntersectionObserver| {
+                for entry in entries.iter() {
+                    if let Ok(entry) = entry.dyn_into::<IntersectionObserverEntry>() {
+                        callback(entry);
+                    }
+                }
+            },
+        )
+            as Box<dyn FnMut(js_sys::Array, IntersectionObserver)>);
+        let init = IntersectionObserverInit::new();
+        init.set_root_margin(root_margin);
+        init.set_threshold(&JsValue::from_f64(threshold));
+        let observer =
+            IntersectionObserver::new_with_options(closure.as_ref().unchecked_ref(), &init)
+                .unwrap();
+        observer.observe(target);
+        IntersectionWatcher {
+            observer,
+            _closure: closure,
+        }
+    }
+}
+
+impl Drop for IntersectionWatcher {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// ResizeObserver 的封装，元素大小改变时回调，drop 时自动 disconnect
+pub struct ResizeWatcher {
+    observer: ResizeObserver,
+    _closure: Closure<dyn FnMut(js_sys::Array, ResizeObserver)>,
+}
+
+impl ResizeWatcher {
+    /// 开始观察时会先回调一次，不支持 ResizeObserver 时返回 None
+    pub fn new<F>(target: &Element, mut callback: F) -> Option<Self>
+    where
+        F: FnMut() + 'static,
+    {
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let closure = Closure::wrap(
+            Box::new(move |_: js_sys::Array, _: ResizeObserver| callback())
+                as Box<dyn FnMut(js_sys::Array, ResizeObserver)>,
+        );
+        let observer = ResizeObserver::new(closure.as_ref().unchecked_ref()).ok()?;
+        observer.observe(target);
+        Some(ResizeWatcher {
+            observer,
+            _closure: closure,
+        })
+    }
+}
+
+impl Drop for ResizeWatcher {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// matchMedia 的封装，匹配结果改变时回调，drop 时自动移除监听
+pub struct MediaQueryWatcher {
+    list: MediaQueryList,
+    closure: Closure<dyn FnMut()>,
+}
+
+impl MediaQueryWatcher {
+    /// query 如 "(prefers-color-scheme: dark)"，没有 window 时返回 None
+    pub fn new<F>(query: &str, mut callback: F) -> Option<Self>
+    where
+        F: FnMut(bool) + 'static,
+    {
+        let list = match_media(query)?;
+        let list_clone = list.clone();
+        let closure =
+            Closure::wrap(Box::new(move || callback(list_clone.matches())) as Box<dyn FnMut()>);
+        list.add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())
+            .ok()?;
+        Some(MediaQueryWatcher { list, closure })
+    }
+
+    pub fn matches(&self) -> bool {
+        self.list.matches()
+    }
+}
+
+impl Drop for MediaQueryWatcher {
+    fn drop(&mut self) {
+        let _ = self
+            .list
+            .remove_event_listener_with_callback("change", self.closure.as_ref().unchecked_ref());
+    }
+}
+
+/// window 事件监听的封装，drop 时自动移除监听
+pub struct WindowEventWatcher {
+    event: &'static str,
+    closure: Closure<dyn FnMut(Event)>,
+}
+
+impl WindowEventWatcher {
+    /// event 如 "keydown"，没有 window 时返回 None
+    pub fn new<F>(event: &'static str, callback: F) -> Option<Self>
+    where
+        F: FnMut(Event) + 'static,
+    {
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let closure = Closure::wrap(Box::new(callback) as Box<dyn FnMut(Event)>);
+        web_sys::window()?
+            .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+            .ok()?;
+        Some(WindowEventWatcher { event, closure })
+    }
+}
+
+impl Drop for WindowEventWatcher {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                self.event,
+                self.closure.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    // 非 wasm 环境（如服务端渲染）无法访问 window
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?.match_media(query).ok()?
+}
+
+/// 当前是否匹配媒体查询，没有 window 时（如 SSR）为 false
+pub fn media_matches(query: &str) -> bool {
+    match_media(query).map(|x| x.matches()).unwrap_or(false)
+}
@@ -0,0 +1,473 @@
+use crate::prelude::{
+    BorderStyle, Breakpoints, Display, Duration, Opacity, Position, Radius, Size, Spacing, Theme,
+    ZIndex,
+};
+use crate::utils::{expand_sides, split_values};
+
+/// 工具类设置的是整个值、某一边（上 右 下 左），还是宽或高
+#[derive(Clone, PartialEq, Debug)]
+enum Part {
+    All,
+    Side(usize),
+    Axis(usize),
+}
+
+/// (属性名称, 修改的部分, 值)
+type Entry = (String, Part, String);
+
+/// sx 工具类解析后的样式，按断点分组，默认值的分组在最前
+/// "p-4 md:px-2 hover:bg-primary-600" => [("", [padding 16px, h_bg_color #2563eb]), ("md", [padding 右左 8px])]
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct SxStyle {
+    groups: Vec<(String, Vec<Entry>)>,
+    pub unknown: Vec<String>,
+}
+
+impl SxStyle {
+    pub fn parse(sx: &str, theme: &Theme, breakpoints: &Breakpoints) -> Self {
+        let mut style = SxStyle {
+            groups: vec![(String::new(), vec![])],
+            unknown: vec![],
+        };
+        for token in split_values(sx) {
+            let mut rest = token.as_str();
+            let mut group = String::new();
+            let mut variant = "";
+            let mut is_known = true;
+            while let Some((prefix, utility)) = rest.split_once(":").filter(|x| !x.0.contains("["))
+            {
+                match prefix {
+                    "hover" => variant = "h_",
+                    "dark" => variant = "d_",
+                    _ if breakpoints.get_query(prefix).is_some() => group = prefix.to_owned(),
+                    _ => is_known = false,
+                }
+                rest = utility;
+            }
+            let entries = match get_utility(rest, theme) {
+                Some(entries) if is_known => entries,
+                _ => {
+                    style.unknown.push(token.clone());
+                    continue;
+                }
+            };
+            let entries = entries
+                .into_iter()
+                .map(|(field, part, value)| (format!("{}{}", variant, field), part, value))
+                .collect::<Vec<_>>();
+            if variant == "h_" && entries.iter().any(|x| !HOVER_FIELDS.contains(&&x.0[2..])) {
+                style.unknown.push(token.clone());
+                continue;
+            }
+            if variant == "d_" && entries.iter().any(|x| !DARK_FIELDS.contains(&&x.0[2..])) {
+                style.unknown.push(token.clone());
+                continue;
+            }
+            match style.groups.iter_mut().find(|x| x.0 == group) {
+                Some(x) => x.1.extend(entries),
+                None => style.groups.push((group, entries)),
+            }
+        }
+        if cfg!(debug_assertions) && !style.unknown.is_empty() {
+            report(&format!(
+                "yew_quick sx: unknown utilities {:?}",
+                style.unknown
+            ));
+        }
+        style
+    }
+
+    /// values 为组件的属性 (名称, 当前值, 是否未在组件中设置)，返回 sx 修改后的属性值
+    /// 只修改未设置的属性，hover dark 属性为空时，以对应的属性为起点
+    pub fn resolve(&self, values: &[(&'static str, String, bool)]) -> Vec<(&'static str, String)> {
+        let mut resolved: Vec<(&'static str, String)> = vec![];
+        let fields = values
+            .iter()
+            .filter(|x| x.2 && !x.0.starts_with("h_") && !x.0.starts_with("d_"))
+            .chain(
+                values
+                    .iter()
+                    .filter(|x| x.2 && (x.0.starts_with("h_") || x.0.starts_with("d_"))),
+            );
+        for (field, current, _) in fields {
+            let start =
+                if current.is_empty() && (field.starts_with("h_") || field.starts_with("d_")) {
+                    let base = &field[2..];
+                    resolved
+                        .iter()
+                        .find(|x| x.0 == base)
+                        .map(|x| x.1.clone())
+                        .or_else(|| values.iter().find(|x| x.0 == base).map(|x| x.1.clone()))
+                        .unwrap_or_default()
+                } else {
+                    current.clone()
+                };
+            if let Some(value) = self.value(field, &start) {
+                resolved.push((field, value));
+            }
+        }
+        resolved
+    }
+
+    /// 默认值 + 各断点的值，"16px md:16px 8px"
+    fn value(&self, field: &str, start: &str) -> Option<String> {
+        let mut base = start.to_owned();
+        let mut points = vec![];
+        let mut is_changed = false;
+        for (group, entries) in &self.groups {
+            let entries = entries.iter().filter(|x| x.0 == field).collect::<Vec<_>>();
+            if entries.is_empty() {
+                continue;
+            }
+            let mut value = base.clone();
+            for (_, part, v) in entries {
+                value = compose(&value, part, v);
+            }
+            if group.is_empty() {
+                base = value;
+            } else {
+                points.push(format!("{}:{}", group, value));
+            }
+            is_changed = true;
+        }
+        if is_changed {
+            Some(
+                std::iter::once(base)
+                    .chain(points)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+const HOVER_FIELDS: [&str; 10] = [
+    "opacity",
+    "padding",
+    "margin",
+    "radius",
+    "border_width",
+    "border_color",
+    "bg_color",
+    "color",
+    "shadow",
+    "size",
+];
+
+const DARK_FIELDS: [&str; 4] = ["bg_color", "color", "border_color", "shadow"];
+
+fn compose(from: &str, part: &Part, value: &str) -> String {
+    match part {
+        Part::All => value.to_owned(),
+        Part::Side(i) => {
+            let mut sides = expand_sides(from);
+            sides[*i] = value.to_owned();
+            sides.join(" ")
+        }
+        Part::Axis(i) => {
+            let v = split_values(from);
+            let mut axis = match v.len() {
+                0 => ["auto".to_owned(), "auto".to_owned()],
+                1 => [v[0].clone(), v[0].clone()],
+                _ => [v[0].clone(), v[1].clone()],
+            };
+            axis[*i] = value.to_owned();
+            axis.join(" ")
+        }
+    }
+}
+
+/// 输出警告，wasm 中为 console.warn
+pub fn report(message: &str) {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::console::warn_1(&message.into());
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// "[10px_20px]" => "10px 20px"
+fn arbitrary(value: &str) -> Option<String> {
+    value
+        .strip_prefix("[")
+        .and_then(|x| x.strip_suffix("]"))
+        .map(|x| x.replace("_", " "))
+}
+
+fn format_number(number: f64) -> String {
+    ((number * 10000.0).round() / 10000.0).to_string()
+}
+
+/// 间距刻度，"4" => 16px，"px" => 1px，"1/2" => 50%
+fn spacing_value(value: &str, is_negative: bool, theme: &Theme) -> Option<String> {
+    let sign = if is_negative { "-" } else { "" };
+    if let Some(x) = arbitrary(value) {
+        return Some(format!("{}{}", sign, x));
+    }
+    let value = match value {
+        "px" => "1px".to_owned(),
+        "full" => "100%".to_owned(),
+        "auto" if !is_negative => return Some("auto".to_owned()),
+        _ => match value.split_once("/") {
+            Some((a, b)) => {
+                let (a, b) = (a.parse::<f64>().ok()?, b.parse::<f64>().ok()?);
+                format!("{}%", format_number(a * 100.0 / b))
+            }
+            None => match value.parse::<f64>().ok()? * theme.spacing {
+                0.0 => "0".to_owned(),
+                x => format!("{}px", format_number(x)),
+            },
+        },
+    };
+    if value == "0" {
+        Some(value)
+    } else {
+        Some(format!("{}{}", sign, value))
+    }
+}
+
+fn color_value(value: &str, theme: &Theme) -> Option<String> {
+    arbitrary(value).or_else(|| theme.get_color(value))
+}
+
+type Entries = Vec<(&'static str, Part, String)>;
+
+fn all(field: &'static str, value: String) -> Entries {
+    vec![(field, Part::All, value)]
+}
+
+fn sides(field: &'static str, list: &[usize], value: String) -> Entries {
+    list.iter()
+        .map(|i| (field, Part::Side(*i), value.clone()))
+        .collect()
+}
+
+fn axis(field: &'static str, list: &[usize], value: String) -> Entries {
+    list.iter()
+        .map(|i| (field, Part::Axis(*i), value.clone()))
+        .collect()
+}
+
+/// 单个工具类对应的属性
+fn get_utility(utility: &str, theme: &Theme) -> Option<Entries> {
+    let (is_negative, utility) = match utility.strip_prefix("-") {
+        Some(x) => (true, x),
+        None => (false, utility),
+    };
+    let display = match utility {
+        "block" => Some("block"),
+        "inline-block" => Some("inline-block"),
+        "inline" => Some("inline"),
+        "grid" => Some("grid"),
+        "inline-grid" => Some("inline-grid"),
+        "flow-root" => Some("flow-root"),
+        "contents" => Some("contents"),
+        "hidden" => Some("none"),
+        _ => None,
+    };
+    if let Some(x) = display {
+        return Some(all("display", x.to_owned()));
+    }
+    if ["static", "fixed", "absolute", "relative", "sticky"].contains(&utility) {
+        return Some(all("position", utility.to_owned()));
+    }
+    match utility {
+        "border" => {
+            let mut entries = all("border_width", "1".to_owned());
+            entries.extend(all("border_style", "solid".to_owned()));
+            return Some(entries);
+        }
+        "rounded" => return Some(all("radius", theme.get_radius("")?)),
+        "shadow" => return Some(all("shadow", theme.get_shadow("")?)),
+        _ => (),
+    }
+    let (name, value) = utility.split_once("-")?;
+    let (name, value) = match (name, value.split_once("-")) {
+        ("min" | "max", Some((axis, value))) => match axis {
+            "w" => (if name == "min" { "min-w" } else { "max-w" }, value),
+            "h" => (if name == "min" { "min-h" } else { "max-h" }, value),
+            _ => return None,
+        },
+        _ => (name, value),
+    };
+    let spacing = |field: &'static str, list: &[usize]| {
+        spacing_value(value, is_negative, theme).map(|v| sides(field, list, v))
+    };
+    let size = |field: &'static str, list: &[usize]| {
+        let v = match value {
+            "screen" if list == [0] => "100vw".to_owned(),
+            "screen" if list == [1] => "100vh".to_owned(),
+            _ => spacing_value(value, is_negative, theme)?,
+        };
+        Some(axis(field, list, v))
+    };
+    match name {
+        "p" => spacing("padding", &[0, 1, 2, 3]),
+        "px" => spacing("padding", &[1, 3]),
+        "py" => spacing("padding", &[0, 2]),
+        "pt" => spacing("padding", &[0]),
+        "pr" => spacing("padding", &[1]),
+        "pb" => spacing("padding", &[2]),
+        "pl" => spacing("padding", &[3]),
+        "m" => spacing("margin", &[0, 1, 2, 3]),
+        "mx" => spacing("margin", &[1, 3]),
+        "my" => spacing("margin", &[0, 2]),
+        "mt" => spacing("margin", &[0]),
+        "mr" => spacing("margin", &[1]),
+        "mb" => spacing("margin", &[2]),
+        "ml" => spacing("margin", &[3]),
+        "w" => size("size", &[0]),
+        "h" => size("size", &[1]),
+        "size" => size("size", &[0, 1]),
+        "min-w" => size("min_size", &[0]),
+        "min-h" => size("min_size", &[1]),
+        "max-w" => size("max_size", &[0]),
+        "max-h" => size("max_size", &[1]),
+        "top" | "right" | "bottom" | "left" => {
+            let field = match name {
+                "top" => "top",
+                "right" => "right",
+                "bottom" => "bottom",
+                _ => "left",
+            };
+            Some(all(field, spacing_value(value, is_negative, theme)?))
+        }
+        "inset" => {
+            let v = spacing_value(value, is_negative, theme)?;
+            Some(
+                ["top", "right", "bottom", "left"]
+                    .iter()
+                    .flat_map(|field| all(field, v.clone()))
+                    .collect(),
+            )
+        }
+        "bg" => Some(all("bg_color", color_value(value, theme)?)),
+        "text" => match theme.get_font_size(value) {
+            Some(v) => Some(all("font_size", v)),
+            None => Some(all("color", color_value(value, theme)?)),
+        },
+        "border" => {
+            if ["solid", "dashed", "dotted", "double", "none"].contains(&value) {
+                Some(all("border_style", value.to_owned()))
+            } else if value.parse::<f64>().is_ok() {
+                Some(all("border_width", value.to_owned()))
+            } else {
+                Some(all("border_color", color_value(value, theme)?))
+            }
+        }
+        "rounded" => Some(all("radius", theme.get_radius(value)?)),
+        "shadow" => Some(all("shadow", theme.get_shadow(value)?)),
+        "opacity" => Some(all(
+            "opacity",
+            format_number(value.parse::<f64>().ok()? / 100.0),
+        )),
+        "z" => Some(all(
+            "z_index",
+            if value == "auto" {
+                value.to_owned()
+            } else {
+                format!(
+                    "{}{}",
+                    if is_negative { "-" } else { "" },
+                    value.parse::<i32>().ok()?
+                )
+            },
+        )),
+        "duration" => Some(all("duration", format!("{}ms", value.parse::<f64>().ok()?))),
+        _ => None,
+    }
+}
+
+/// 可以由 sx 设置的属性类型
+pub trait SxValue: Sized {
+    fn to_sx(&self) -> String;
+    fn from_sx(value: &str) -> Option<Self>;
+}
+
+macro_rules! impl_sx_value_string {
+    ($($ty:ty),*) => {
+        $(
+            impl SxValue for $ty {
+                fn to_sx(&self) -> String {
+                    self.to_string()
+                }
+                fn from_sx(value: &str) -> Option<Self> {
+                    Some(value.to_owned().into())
+                }
+            }
+        )*
+    };
+}
+impl_sx_value_string!(String, Size, Spacing, Radius, Opacity, Duration, ZIndex);
+
+macro_rules! impl_sx_value_enum {
+    ($ty:ident, $($variant:ident),*) => {
+        impl SxValue for $ty {
+            fn to_sx(&self) -> String {
+                self.get_name()
+            }
+            fn from_sx(value: &str) -> Option<Self> {
+                [$($ty::$variant),*].into_iter().find(|x| x.get_name() == value)
+            }
+        }
+    };
+}
+impl_sx_value_enum!(
+    Display,
+    Block,
+    Inline,
+    InlineBlock,
+    Grid,
+    InlineGrid,
+    FlowRoot,
+    None,
+    Contents
+);
+impl_sx_value_enum!(Position, Absolute, Fixed, Relative, Static, Sticky, Inherit);
+impl_sx_value_enum!(
+    BorderStyle,
+    No,
+    Hidden,
+    Dotted,
+    Dashed,
+    Solid,
+    Double,
+    Groove,
+    Ridge,
+    Inset,
+    Outset
+);
+
+/// 解析后的属性中未在组件中设置的属性，替换为 sx 工具类的值
+macro_rules! sx_props {
+    ($p:expr, $props:expr, $sx:expr, $($field:ident),* $(,)?) => {{
+        use crate::prelude::StyleProp;
+        use crate::utils::SxValue;
+        let values = vec![
+            $(
+                (stringify!($field), $p.$field.to_sx(), $props.$field.get().is_none()),
+            )*
+        ];
+        let resolved = $sx.resolve(&values);
+        $(
+            if let Some(value) = resolved.iter().find(|x| x.0 == stringify!($field)) {
+                match SxValue::from_sx(&value.1) {
+                    Some(value) => $p.$field = value,
+                    // 枚举类型的属性不支持断点，如 "block md:none"
+                    None => {
+                        if cfg!(debug_assertions) {
+                            crate::utils::report(&format!(
+                                "yew_quick sx: {} does not support {:?}",
+                                stringify!($field),
+                                value.1
+                            ));
+                        }
+                    }
+                }
+            }
+        )*
+    }};
+}
+pub(crate) use sx_props;
@@ -0,0 +1,177 @@
+/// 检查是否有单位,和计算
+pub fn is_have_unit(data: &str) -> bool {
+    // px pt pc rem em mm in vw vh calc auto
+    if data.contains("auto") {
+        return true;
+    }
+    if data.contains("p") {
+        return true;
+    }
+    if data.contains("e") {
+        return true;
+    }
+    if data.contains("v") {
+        return true;
+    }
+    if data.contains("m") {
+        return true;
+    }
+    if data.contains("c") {
+        return true;
+    }
+    if data.contains("i") {
+        return true;
+    }
+    false
+}
+
+/// 将 + - * / 两边加上空格
+pub fn add_op_space(data: &str) -> String {
+    if data.contains("+") {
+        let new_data = data.replace("+", " + ");
+        return new_data.to_string();
+    }
+    if data.contains("-") {
+        let new_data = data.replace("-", " - ");
+        return new_data.to_string();
+    }
+    if data.contains("*") {
+        let new_data = data.replace("*", " * ");
+        return new_data.to_string();
+    }
+    if data.contains("/") {
+        let new_data = data.replace("/", " / ");
+        return new_data.to_string();
+    }
+    data.to_string()
+}
+
+/// 按空格拆分多个值，括号内的空格不拆分 "calc(1px + 2px) 3px"
+pub fn split_values(data: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut temp = String::new();
+    let mut depth = 0;
+    for c in data.chars() {
+        match c {
+            '(' => {
+                depth += 1;
+                temp.push(c);
+            }
+            ')' => {
+                depth -= 1;
+                temp.push(c);
+            }
+            ' ' if depth == 0 => {
+                if !temp.is_empty() {
+                    values.push(temp.clone());
+                    temp.clear();
+                }
+            }
+            _ => temp.push(c),
+        }
+    }
+    if !temp.is_empty() {
+        values.push(temp);
+    }
+    values
+}
+
+/// 将 padding margin 的简写，展开为 上 右 下 左
+pub fn expand_sides(data: &str) -> [String; 4] {
+    let v = split_values(data);
+    match v.len() {
+        0 => [
+            "0".to_owned(),
+            "0".to_owned(),
+            "0".to_owned(),
+            "0".to_owned(),
+        ],
+        1 => [v[0].clone(), v[0].clone(), v[0].clone(), v[0].clone()],
+        2 => [v[0].clone(), v[1].clone(), v[0].clone(), v[1].clone()],
+        3 => [v[0].clone(), v[1].clone(), v[2].clone(), v[1].clone()],
+        _ => [v[0].clone(), v[1].clone(), v[2].clone(), v[3].clone()],
+    }
+}
+
+/// 在原有的 padding margin 上，加上安全区域
+/// inset_fn 为 "env" 或 "constant"（兼容旧版 iOS WebView）
+pub fn safe_area_value(data: &str, sides: [bool; 4], inset_fn: &str) -> String {
+    if !sides.contains(&true) {
+        return data.to_owned();
+    }
+    let names = ["top", "right", "bottom", "left"];
+    expand_sides(data)
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            if !sides[i] {
+                x.clone()
+            } else if x == "0" || x == "0px" {
+                format!("{}(safe-area-inset-{})", inset_fn, names[i])
+            } else {
+                format!("calc({} + {}(safe-area-inset-{}))", x, inset_fn, names[i])
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// 组件属性解析后的值，样式属性在组件属性中未设置时 StyleProp::get 为 None
+/// 优先级：组件中设置的属性 > sx 和 preset > ConfigProvider 中设置的属性 > 内置默认值 $default
+/// 其余属性原样复制
+macro_rules! resolved_props {
+    (
+        $(#[$meta:meta])*
+        $name:ident for $props:ident {
+            $($field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+        $($rest:ident: $rest_ty:ty),* $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq)]
+        pub(crate) struct $name {
+            $(pub $field: $ty,)*
+            $(pub $rest: $rest_ty,)*
+        }
+        impl $name {
+            /// 内置默认值，加上 config 中设置的样式属性，不含组件中设置的样式属性
+            pub(crate) fn new(props: &$props, config: Option<&$props>) -> Self {
+                let mut p = $name {
+                    $($field: $default,)*
+                    $($rest: props.$rest.clone(),)*
+                };
+                if let Some(config) = config {
+                    p.apply(config);
+                }
+                p
+            }
+
+            /// 使用 props 中设置的样式属性
+            pub(crate) fn apply(&mut self, props: &$props) {
+                use crate::prelude::StyleProp;
+                $(
+                    if let Some(value) = props.$field.get() {
+                        self.$field = value.clone();
+                    }
+                )*
+            }
+        }
+    };
+}
+pub(crate) use resolved_props;
+
+#[cfg(test)]
+mod tests {
+    use super::is_have_unit;
+
+    #[test]
+    fn auto_has_unit() {
+        // "auto" 不能再加 px
+        assert!(is_have_unit("auto"));
+        assert!(is_have_unit("10px"));
+        assert!(is_have_unit("2rem"));
+        assert!(is_have_unit("calc(100% - 10px)"));
+        assert!(!is_have_unit("10"));
+        assert!(!is_have_unit("50%"));
+    }
+}
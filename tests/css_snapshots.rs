@@ -0,0 +1,243 @@
+//! 组件生成 css 的快照测试
+//! 快照保存在 tests/snapshots，UPDATE_SNAPSHOTS=1 cargo test 生成或更新全部快照，快照不存在时测试失败
+
+use std::{env, fs, path::PathBuf};
+
+use yew::props;
+use yew_quick::prelude::*;
+
+fn assert_snapshot(name: &str, css: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.css", name));
+    if env::var("UPDATE_SNAPSHOTS").is_ok() {
+        fs::write(&path, css).unwrap();
+        return;
+    }
+    if !path.exists() {
+        panic!(
+            "snapshot {} does not exist, run with UPDATE_SNAPSHOTS=1 to create it",
+            name
+        );
+    }
+    let expected = fs::read_to_string(&path).unwrap();
+    if expected != css {
+        let diff = expected
+            .lines()
+            .zip(css.lines())
+            .enumerate()
+            .find(|(_, (a, b))| a != b)
+            .map(|(i, (a, b))| format!("line {}:\n- {}\n+ {}", i + 1, a, b))
+            .unwrap_or_else(|| "length differs".to_owned());
+        panic!(
+            "snapshot {} does not match, run with UPDATE_SNAPSHOTS=1 to update\n{}",
+            name, diff
+        );
+    }
+}
+
+#[test]
+fn box_default() {
+    assert_snapshot("box_default", &box_css(&props!(BoxProps {})));
+}
+
+#[test]
+fn box_size_units() {
+    let p = props!(BoxProps {
+        size: "100 50%",
+        min_size: "calc(100%-20px) 10vh",
+        padding: (10, 20),
+        margin: "0 auto",
+        radius: "4 50%",
+        top: "10",
+        left: "1.5vw",
+    });
+    assert_snapshot("box_size_units", &box_css(&p));
+}
+
+#[test]
+fn box_rpx() {
+    let p = props!(BoxProps {
+        size: "750rpx 100rpx",
+        padding: "20rpx 10",
+    });
+    assert_snapshot("box_rpx", &box_css(&p));
+}
+
+#[test]
+fn box_rpx_calc() {
+    // 函数参数中的 rpx 也换算
+    let p = props!(BoxProps {
+        size: "calc(100%-40rpx) calc(50vh+0rpx)",
+        margin: "max(20rpx,10px) 0",
+    });
+    assert_snapshot("box_rpx_calc", &box_css(&p));
+}
+
+#[test]
+fn box_responsive() {
+    let p = props!(BoxProps {
+        size: "100 md:200 lg:50%",
+        display: Display::Block,
+    });
+    assert_snapshot("box_responsive", &box_css(&p));
+}
+
+#[test]
+fn box_responsive_revert() {
+    // lg 与默认值相同，仍需写回 lg 的规则覆盖 md
+    let p = props!(BoxProps {
+        size: "100 md:200 lg:100",
+        display: Display::Block,
+    });
+    assert_snapshot("box_responsive_revert", &box_css(&p));
+}
+
+#[test]
+fn box_responsive_inherit() {
+    // lg 只设置 padding，size 沿用 md，不写回默认值
+    let p = props!(BoxProps {
+        size: "100 md:200",
+        padding: "0 lg:10",
+        display: Display::Block,
+    });
+    assert_snapshot("box_responsive_inherit", &box_css(&p));
+}
+
+#[test]
+fn box_hover_fallback() {
+    let p = props!(BoxProps {
+        bg_color: "#fff",
+        color: "#333",
+        padding: "10",
+        h_bg_color: "#eee",
+        duration: "300ms",
+    });
+    assert_snapshot("box_hover_fallback", &box_css(&p));
+}
+
+#[test]
+fn box_dark() {
+    let p = props!(BoxProps {
+        bg_color: "#fff",
+        color: "#333",
+        d_bg_color: "#111",
+        d_color: "#eee",
+    });
+    assert_snapshot("box_dark", &box_css(&p));
+}
+
+#[test]
+fn box_container() {
+    // 设置后才输出 container-type container-name
+    let p = props!(BoxProps {
+        container_type: ContainerType::InlineSize,
+        container_name: "card",
+    });
+    assert_snapshot("box_container", &box_css(&p));
+}
+
+#[test]
+fn box_sx() {
+    let p = props!(BoxProps {
+        sx: "p-4 w-[120px] bg-primary rounded-lg hover:bg-primary-600 dark:bg-gray-800",
+    });
+    assert_snapshot("box_sx", &box_css(&p));
+}
+
+#[test]
+fn flex_ways() {
+    for (name, flex) in [
+        ("flex_frss", FlexWay::Frss),
+        ("flex_frbc", FlexWay::Frbc),
+        ("flex_fcce", FlexWay::Fcce),
+        ("flex_fraa", FlexWay::Fraa),
+    ] {
+        assert_snapshot(name, &flex_css(&props!(FlexProps { flex })));
+    }
+}
+
+#[test]
+fn flex_hover_dark() {
+    let p = props!(FlexProps {
+        flex: FlexWay::Frbc,
+        size: "100% 60",
+        h_bg_color: "#f5f5f5",
+        d_bg_color: "#222",
+    });
+    assert_snapshot("flex_hover_dark", &flex_css(&p));
+}
+
+#[test]
+fn text_font() {
+    let p = props!(TextProps {
+        font_size: "14 md:16",
+        color: "#333",
+        h_color: "red",
+        d_color: "#ddd",
+    });
+    assert_snapshot("text_font", &text_css(&p));
+}
+
+#[test]
+fn text_ellipsis_lines() {
+    let p = props!(TextEllipsisProps {
+        line: String::from("2"),
+        size: "200 auto",
+    });
+    assert_snapshot("text_ellipsis_lines", &text_ellipsis_css(&p));
+}
+
+#[test]
+fn image_size() {
+    let p = props!(ImageProps {
+        src: String::from("a.png"),
+        size: "100 100",
+        radius: "50%",
+    });
+    assert_snapshot("image_size", &image_css(&p));
+}
+
+#[test]
+fn line_default() {
+    assert_snapshot("line_default", &line_css(&props!(LineProps {})));
+}
+
+#[test]
+fn image_fit_modes() {
+    for (name, mode) in [
+        ("image_aspect_fill", ImageFit::AspectFill),
+        ("image_width_fix", ImageFit::WidthFix),
+        ("image_top_left", ImageFit::TopLeft),
+    ] {
+        let p = props!(ImageProps {
+            src: String::from("a.png"),
+            size: "100 80",
+            mode,
+        });
+        assert_snapshot(name, &image_css(&p));
+    }
+}
+
+#[test]
+fn image_placeholders() {
+    for (name, placeholder) in [
+        (
+            "image_placeholder_color",
+            Placeholder::Color(String::from("#eee")),
+        ),
+        (
+            "image_placeholder_blur",
+            Placeholder::Blur(String::from("small.jpg")),
+        ),
+        ("image_placeholder_skeleton", Placeholder::Skeleton),
+    ] {
+        let p = props!(ImageProps {
+            src: String::from("a.png"),
+            size: "100 80",
+            placeholder,
+            fade: "300ms",
+        });
+        assert_snapshot(name, &image_css(&p));
+    }
+}
@@ -0,0 +1,7 @@
+//! q! 的编译失败用例，错误信息保存在 tests/ui/*.stderr，TRYBUILD=overwrite cargo test 更新
+
+#[test]
+fn invalid_values() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,437 @@
+use yew::prelude::*;
+use yew_quick::prelude::*;
+use yew_quick::testing::{render, render_html};
+
+#[function_component]
+fn Card() -> Html {
+    html! {
+        <Flex flex={FlexWay::Frbc} testid="card" size="100% md:50%" h_bg_color="#eee" d_bg_color="#111">
+            <Text testid="title" font_size="14" color="#333">{"标题"}</Text>
+            <div data-testid="extra" class="extra">{"a & b"}<br/>{"c"}</div>
+            <Image testid="cover" src="a.png" size="40 40" />
+        </Flex>
+    }
+}
+
+#[test]
+fn find_by_testid() {
+    let page = render::<Card>();
+    let card = page.get_by_testid("card");
+    assert_eq!(card.tag(), "div");
+    assert_eq!(card.text(), "标题a & bc");
+    assert_eq!(card.children().len(), 3);
+    assert_eq!(page.get_by_testid("title").tag(), "span");
+    assert_eq!(page.get_by_testid("extra").attr("class"), Some("extra"));
+    assert_eq!(page.get_by_testid("cover").attr("src"), Some("a.png"));
+    assert!(page.find_by_testid("missing").is_none());
+    assert_eq!(page.find_all_by_tag("img").len(), 1);
+}
+
+#[test]
+fn computed_declarations() {
+    let page = render::<Card>();
+    let card = page.get_by_testid("card");
+    assert_eq!(card.declaration("display").as_deref(), Some("flex"));
+    assert_eq!(
+        card.declaration("justify-content").as_deref(),
+        Some("space-between")
+    );
+    assert_eq!(card.declaration("width").as_deref(), Some("100%"));
+    assert_eq!(
+        card.media_declaration("(min-width: 768px)", "width")
+            .as_deref(),
+        Some("50%")
+    );
+    assert_eq!(
+        card.hover_declaration("background-color").as_deref(),
+        Some("#eee")
+    );
+    assert_eq!(
+        card.dark_declaration("background-color").as_deref(),
+        Some("#111")
+    );
+
+    let title = page.get_by_testid("title");
+    assert_eq!(title.declaration("font-size").as_deref(), Some("14px"));
+    assert_eq!(title.declaration("color").as_deref(), Some("#333"));
+    assert!(title.css().contains("font-size: 14px;"));
+
+    let extra = page.get_by_testid("extra");
+    assert_eq!(extra.declaration("width"), None);
+    assert_eq!(extra.css(), "");
+}
+
+#[test]
+fn render_snippet() {
+    let page = render_html(html! {
+        <Box testid="box" padding="10 20" sx="rounded-lg">{"hi"}</Box>
+    });
+    let node = page.get_by_testid("box");
+    assert_eq!(node.text(), "hi");
+    assert_eq!(node.declaration("padding").as_deref(), Some("10px 20px"));
+    assert_eq!(node.declaration("border-radius").as_deref(), Some("8px"));
+    assert!(page.css().contains("padding: 10px 20px;"));
+}
+
+#[test]
+fn lazy_image() {
+    let page = render_html(html! {
+        <>
+            <Image testid="observer" src="a.png" lazy={LazyMode::Observer} />
+            <Image testid="native" src="b.png" lazy={LazyMode::Native} />
+            <Image testid="eager" src="c.png" placeholder={Placeholder::Skeleton} />
+        </>
+    });
+    assert_eq!(page.get_by_testid("observer").attr("src"), None);
+    let native = page.get_by_testid("native");
+    assert_eq!(native.attr("src"), Some("b.png"));
+    assert_eq!(native.attr("loading"), Some("lazy"));
+    let eager = page.get_by_testid("eager");
+    assert_eq!(eager.attr("src"), Some("c.png"));
+    assert_eq!(eager.attr("loading"), None);
+    assert_eq!(
+        eager.declaration("animation").as_deref(),
+        Some("yew-quick-skeleton 1.4s ease infinite")
+    );
+}
+
+#[test]
+fn picture_sources() {
+    let page = render_html(html! {
+        <>
+            <Image testid="plain" src="a.jpg" srcset="a.jpg 1x, a@2x.jpg 2x" />
+            <Image
+                testid="picture"
+                src="b.jpg"
+                sizes="50vw"
+                size="100 100"
+                sources={vec![
+                    ImageSource::new("b.avif").with_type("image/avif"),
+                    ImageSource::new("b.webp").with_type("image/webp").with_media("(min-width: 768px)"),
+                ]}
+            />
+        </>
+    });
+    let plain = page.get_by_testid("plain");
+    assert_eq!(plain.attr("srcset"), Some("a.jpg 1x, a@2x.jpg 2x"));
+    assert_eq!(plain.attr("sizes"), None);
+    assert_eq!(page.find_all_by_tag("picture").len(), 1);
+
+    let picture = &page.find_all_by_tag("picture")[0];
+    assert_eq!(picture.declaration("display").as_deref(), Some("contents"));
+    let children = picture.children();
+    assert_eq!(children.len(), 3);
+    assert_eq!(children[0].attr("type"), Some("image/avif"));
+    assert_eq!(children[0].attr("media"), None);
+    assert_eq!(children[1].attr("srcset"), Some("b.webp"));
+    assert_eq!(children[1].attr("media"), Some("(min-width: 768px)"));
+    let img = children[2];
+    assert_eq!(img.attr("data-testid"), Some("picture"));
+    assert_eq!(img.attr("sizes"), Some("50vw"));
+    assert_eq!(img.declaration("width").as_deref(), Some("100px"));
+}
+
+#[test]
+fn image_preview_closed() {
+    let page = render_html(html! {
+        <>
+            <Image testid="thumb" src="b.jpg" preview={true} preview_images={vec![String::from("a.jpg"), String::from("b.jpg")]} />
+            <ImagePreview images={vec![String::from("a.jpg")]} />
+        </>
+    });
+    assert_eq!(page.get_by_testid("thumb").attr("src"), Some("b.jpg"));
+    assert_eq!(page.find_all_by_tag("img").len(), 1);
+}
+
+#[test]
+fn image_border_and_dark() {
+    let page = render_html(html! {
+        <Image
+            testid="avatar"
+            src="a.png"
+            d_src="a-dark.png"
+            alt="头像"
+            draggable={false}
+            decoding={Decoding::Async}
+            border_width="2"
+            border_color="#fff"
+            border_style={BorderStyle::Solid}
+            shadow="0 2px 4px rgba(0,0,0,.2)"
+            h_border_color="#1890ff"
+            d_border_color="#333"
+            d_brightness="0.8"
+            aspect_ratio="1 / 1"
+        />
+    });
+    let sources = page.find_all_by_tag("source");
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].attr("srcset"), Some("a-dark.png"));
+    assert_eq!(
+        sources[0].attr("media"),
+        Some("(prefers-color-scheme: dark)")
+    );
+
+    let img = page.get_by_testid("avatar");
+    assert_eq!(img.attr("alt"), Some("头像"));
+    assert_eq!(img.attr("draggable"), Some("false"));
+    assert_eq!(img.attr("decoding"), Some("async"));
+    assert_eq!(img.declaration("border-width").as_deref(), Some("2px"));
+    assert_eq!(img.declaration("border-style").as_deref(), Some("solid"));
+    assert_eq!(img.declaration("aspect-ratio").as_deref(), Some("1 / 1"));
+    assert_eq!(
+        img.hover_declaration("border-color").as_deref(),
+        Some("#1890ff")
+    );
+    assert_eq!(
+        img.hover_declaration("box-shadow").as_deref(),
+        Some("0 2px 4px rgba(0,0,0,.2)")
+    );
+    assert_eq!(
+        img.dark_declaration("border-color").as_deref(),
+        Some("#333")
+    );
+    assert_eq!(
+        img.dark_declaration("filter").as_deref(),
+        Some("brightness(0.8)")
+    );
+}
+
+#[test]
+fn image_cropper_before_load() {
+    let cropper = CropperRef::default();
+    let page = render_html(html! {
+        <ImageCropper testid="cropper" src="avatar.jpg" size="300 200" aspect={1.0} cross_origin="anonymous" cropper={cropper.clone()} />
+    });
+    let root = page.get_by_testid("cropper");
+    assert_eq!(root.declaration("width").as_deref(), Some("300px"));
+    assert_eq!(root.declaration("height").as_deref(), Some("200px"));
+    let img = &page.find_all_by_tag("img")[0];
+    assert_eq!(img.attr("src"), Some("avatar.jpg"));
+    assert_eq!(img.attr("crossorigin"), Some("anonymous"));
+    assert_eq!(img.attr("draggable"), Some("false"));
+    // 图片加载前不显示裁剪框，也没有裁剪结果
+    assert!(page
+        .find(|node| node.classes().contains(&"image-cropper-box"))
+        .is_none());
+    assert_eq!(cropper.area(), None);
+    assert_eq!(cropper.to_data_url(100, 100, "image/png", 1.0), None);
+}
+
+#[test]
+fn text_ellipsis_clamp() {
+    let page = render_html(html! {
+        <>
+            <TextEllipsis testid="one">{"单行"}</TextEllipsis>
+            <TextEllipsis testid="inline" line="3" display={Display::InlineBlock} expandable={true}>{"多行"}</TextEllipsis>
+        </>
+    });
+    let one = page.get_by_testid("one");
+    assert_eq!(one.declaration("display").as_deref(), Some("-webkit-box"));
+    assert_eq!(one.declaration("-webkit-line-clamp").as_deref(), Some("1"));
+    let inline = page.get_by_testid("inline");
+    assert_eq!(
+        inline.declaration("display").as_deref(),
+        Some("-webkit-inline-box")
+    );
+    assert_eq!(
+        inline.declaration("-webkit-line-clamp").as_deref(),
+        Some("3")
+    );
+    // 布局前无法检测是否省略，不显示展开
+    assert_eq!(page.find_all_by_tag("span").len(), 2);
+}
+
+#[test]
+fn layout_components_styles() {
+    let page = render_html(html! {
+        <div data-testid="wrap">
+            <SafeArea padding="10" bg_color="#fff" d_bg_color="#000">{"safe"}</SafeArea>
+            <VirtualList
+                item_count={3}
+                render_item={Callback::from(|i: usize| html! { <p>{ i }</p> })}
+                size="100% 300"
+            />
+        </div>
+    });
+    let children = page.get_by_testid("wrap").children();
+    let safe = &children[0];
+    assert_eq!(safe.text(), "safe");
+    assert_eq!(
+        safe.declaration("background-color").as_deref(),
+        Some("#fff")
+    );
+    assert_eq!(
+        safe.dark_declaration("background-color").as_deref(),
+        Some("#000")
+    );
+    let list = &children[1];
+    assert_eq!(list.declaration("height").as_deref(), Some("300px"));
+    assert_eq!(list.declaration("overflow-y").as_deref(), Some("auto"));
+}
+
+#[test]
+fn truncate_positions() {
+    let measure = |x: &str| x.chars().count() as f64;
+    let name = "very-long-file-name.pdf";
+    for (position, expected) in [
+        (TruncatePosition::Start, "…g-file-name.pdf"),
+        (TruncatePosition::Middle, "very-l…e.pdf"),
+        (TruncatePosition::End, "very-long-f…"),
+    ] {
+        let max_width = if position == TruncatePosition::Start {
+            16.0
+        } else {
+            12.0
+        };
+        assert_eq!(
+            truncate_text(name, &position, "…", max_width, measure),
+            expected
+        );
+    }
+    assert_eq!(
+        truncate_text("短", &TruncatePosition::End, "…", 2.0, measure),
+        "短"
+    );
+    assert_eq!(
+        truncate_text(
+            "广东省深圳市南山区",
+            &TruncatePosition::End,
+            "...更多",
+            8.0,
+            measure
+        ),
+        "广东省...更多"
+    );
+
+    // 测量前渲染全文，由 css 省略
+    let page = render_html(html! {
+        <Truncate testid="file" text={name} position={TruncatePosition::Middle} width="120" />
+    });
+    let file = page.get_by_testid("file");
+    assert_eq!(file.text(), name);
+    assert_eq!(file.attr("title"), None);
+    assert_eq!(file.declaration("width").as_deref(), Some("120px"));
+    assert_eq!(file.declaration("white-space").as_deref(), Some("nowrap"));
+}
+
+#[test]
+fn highlight_keywords() {
+    let page = render_html(html! {
+        <Text testid="result" color="#333">
+            <Highlight
+                testid="hit"
+                text="Café au lait, CAFE noir"
+                keywords={vec![String::from("cafe"), String::from("au"), String::from("é au")]}
+                ignore_diacritics={true}
+                color="#b91c1c"
+                d_bg_color="#713f12"
+            />
+        </Text>
+    });
+    assert_eq!(
+        page.get_by_testid("result").text(),
+        "Café au lait, CAFE noir"
+    );
+    let marks = page
+        .find_all_by_tag("mark")
+        .iter()
+        .map(|mark| mark.text())
+        .collect::<Vec<String>>();
+    // "Café" 与 "é au" 重叠，合并为一段
+    assert_eq!(marks, vec!["Café au", "CAFE"]);
+    let hit = page.get_by_testid("hit");
+    assert_eq!(hit.declaration("color").as_deref(), Some("#b91c1c"));
+    assert_eq!(
+        hit.declaration("background-color").as_deref(),
+        Some("#fde68a")
+    );
+    assert_eq!(
+        hit.dark_declaration("background-color").as_deref(),
+        Some("#713f12")
+    );
+
+    let page = render_html(html! {
+        <Highlight text="Café CAFE" keywords={vec![String::from("cafe")]} ignore_case={false} />
+    });
+    assert!(page.find_all_by_tag("mark").is_empty());
+}
+
+#[test]
+fn config_with_explicit_builtin_default() {
+    let config = Config::default().with_box(|p| p.display = Some(Display::Block));
+    let page = render_html(html! {
+        <ConfigProvider {config}>
+            <Box testid="config">{"a"}</Box>
+            <Box testid="explicit" display={Display::InlineBlock}>{"b"}</Box>
+        </ConfigProvider>
+    });
+    assert_eq!(
+        page.get_by_testid("config")
+            .declaration("display")
+            .as_deref(),
+        Some("block")
+    );
+    assert_eq!(
+        page.get_by_testid("explicit")
+            .declaration("display")
+            .as_deref(),
+        Some("inline-block")
+    );
+}
+
+#[test]
+fn named_props_win_over_sx() {
+    let page = render_html(html! {
+        <div>
+            <Box testid="explicit" padding="0" sx="p-4">{"a"}</Box>
+            <Box testid="sx" sx="p-4">{"b"}</Box>
+        </div>
+    });
+    assert_eq!(
+        page.get_by_testid("explicit")
+            .declaration("padding")
+            .as_deref(),
+        Some("0px")
+    );
+    assert_eq!(
+        page.get_by_testid("sx").declaration("padding").as_deref(),
+        Some("16px 16px 16px 16px")
+    );
+}
+
+#[test]
+fn pull_refresh_duration() {
+    let page = render_html(html! {
+        <PullRefresh
+            on_refresh={Callback::from(|_| std::boxed::Box::pin(async {}) as RefreshFuture)}
+            duration="300ms"
+        >
+            <p>{"list"}</p>
+        </PullRefresh>
+    });
+    let track = page
+        .find(|x| x.classes().contains(&"pull-refresh-track"))
+        .unwrap();
+    assert_eq!(
+        track.attr("style"),
+        Some("transform: translate3d(0, 0px, 0); transition-duration: 0.3s;")
+    );
+}
+
+#[function_component]
+fn Layer() -> Html {
+    let z_index = use_z_index();
+    html! { <p data-testid="layer">{ z_index }</p> }
+}
+
+#[test]
+fn z_index_without_cleanup() {
+    // SSR 时浮层不登记，多次渲染不会累加
+    for _ in 0..3 {
+        let page = render::<Layer>();
+        assert_eq!(
+            page.get_by_testid("layer").text(),
+            LAYER_BASE_Z_INDEX.to_string()
+        );
+    }
+}
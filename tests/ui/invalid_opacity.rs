@@ -0,0 +1,5 @@
+use yew_quick::prelude::q;
+
+fn main() {
+    let _ = q!(opacity, "O.5");
+}
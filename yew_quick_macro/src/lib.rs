@@ -0,0 +1,615 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitStr, Token};
+
+/// 编译期校验属性值，校验失败时报出编译错误
+/// ### 使用示例
+///```text
+/// q!("10 20")                       // 长度，1 ~ 4 个值，展开为 "10 20"
+/// q!(size, "100% 50 md:50%")        // Size，1 ~ 2 个值
+/// q!(spacing, "10 20 10 20")        // Spacing，1 ~ 4 个值
+/// q!(color, "#fff md:rgba(0,0,0,.5)") // 颜色，展开为 "#fff md:rgba(0,0,0,.5)"
+/// q!(opacity, "0.5")                // Opacity，0 ~ 1
+/// q!(duration, "300ms")             // Duration，s 或 ms
+/// q!(z_index, "10")                 // ZIndex，整数
+/// q!(flex, "row between center")    // FlexWay::Frbc，也可写为 "rbc"
+///```
+#[proc_macro]
+pub fn q(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as QuickInput);
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct QuickInput {
+    kind: Option<Ident>,
+    value: LitStr,
+}
+
+impl Parse for QuickInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(QuickInput {
+                kind: None,
+                value: input.parse()?,
+            });
+        }
+        let kind: Ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let value: LitStr = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the value"));
+        }
+        Ok(QuickInput {
+            kind: Some(kind),
+            value,
+        })
+    }
+}
+
+impl QuickInput {
+    fn expand(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let value = self.value.value();
+        let span = self.value.span();
+        let lit = &self.value;
+        let kind = match &self.kind {
+            Some(kind) => kind.to_string(),
+            None => {
+                check_groups(&value, span, "length", 4, is_length, LENGTH_HINT)?;
+                return Ok(quote!(#lit));
+            }
+        };
+        let ty = match kind.as_str() {
+            "size" => {
+                check_groups(&value, span, "size", 2, is_length, LENGTH_HINT)?;
+                "Size"
+            }
+            "spacing" => {
+                check_groups(&value, span, "spacing", 4, is_length, LENGTH_HINT)?;
+                "Spacing"
+            }
+            "color" => {
+                check_groups(&value, span, "color", 1, is_color, COLOR_HINT)?;
+                return Ok(quote!(#lit));
+            }
+            "opacity" => {
+                check_groups(&value, span, "opacity", 1, is_opacity, OPACITY_HINT)?;
+                "Opacity"
+            }
+            "duration" => {
+                check_groups(&value, span, "duration", 1, is_duration, DURATION_HINT)?;
+                "Duration"
+            }
+            "z_index" => {
+                check_groups(&value, span, "z_index", 1, is_z_index, Z_INDEX_HINT)?;
+                "ZIndex"
+            }
+            "flex" => {
+                let variant = format_ident!("{}", flex_way(&value, span)?);
+                return Ok(quote!(::yew_quick::prelude::FlexWay::#variant));
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    self.kind.as_ref().unwrap().span(),
+                    format!(
+                        "unknown kind `{}`, expected one of: size, spacing, color, opacity, duration, z_index, flex",
+                        kind
+                    ),
+                ))
+            }
+        };
+        let ty = format_ident!("{}", ty);
+        Ok(quote!(::yew_quick::prelude::#ty::from(#lit)))
+    }
+}
+
+const LENGTH_HINT: &str =
+    "expected a number (px), a length such as `10px` `2rem` `50%` `20rpx`, `auto`, or `calc(...)`";
+const COLOR_HINT: &str =
+    "expected a hex color such as `#fff` `#181818`, `rgb(...)` `rgba(...)` `hsl(...)`, or a named color";
+const OPACITY_HINT: &str = "expected a number between 0 and 1, or a percentage";
+const DURATION_HINT: &str =
+    "expected seconds such as `0.3` `0.3s`, or milliseconds such as `300ms`";
+const Z_INDEX_HINT: &str = "expected an integer or `auto`";
+
+/// 按断点分组校验，"100% md:50% 20" => [["100%"], ["50%", "20"]]
+fn check_groups(
+    value: &str,
+    span: Span,
+    kind: &str,
+    max: usize,
+    check: fn(&str) -> bool,
+    hint: &str,
+) -> syn::Result<()> {
+    let mut groups: Vec<(String, Vec<String>)> = vec![(String::new(), vec![])];
+    for x in split_values(value) {
+        match split_point(&x) {
+            Some((name, v)) => groups.push((name.to_owned(), vec![v.to_owned()])),
+            None => groups.last_mut().unwrap().1.push(x),
+        }
+    }
+    for (name, values) in &groups {
+        let at = if name.is_empty() {
+            String::new()
+        } else {
+            format!(" at breakpoint `{}`", name)
+        };
+        if values.is_empty() {
+            if name.is_empty() && groups.len() > 1 {
+                continue;
+            }
+            return Err(syn::Error::new(
+                span,
+                format!("empty {} value{}, {}", kind, at, hint),
+            ));
+        }
+        if values.len() > max {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "too many values for {}{}: found {}, expected at most {}",
+                    kind,
+                    at,
+                    values.len(),
+                    max
+                ),
+            ));
+        }
+        for v in values {
+            if !check(v) {
+                return Err(syn::Error::new(
+                    span,
+                    format!("invalid {} `{}`{}, {}", kind, v, at, hint),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// "md:50%" => ("md", "50%")，"@md/card:50%" => ("@md/card", "50%")
+fn split_point(x: &str) -> Option<(&str, &str)> {
+    let (name, value) = x.split_once(':')?;
+    let is_name = !name.is_empty()
+        && name
+            .trim_start_matches('@')
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '/');
+    if is_name {
+        Some((name, value))
+    } else {
+        None
+    }
+}
+
+/// 按空格拆分，括号内的空格不拆分
+fn split_values(data: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut temp = String::new();
+    let mut depth = 0;
+    for c in data.chars() {
+        match c {
+            '(' => {
+                depth += 1;
+                temp.push(c);
+            }
+            ')' => {
+                depth -= 1;
+                temp.push(c);
+            }
+            ' ' if depth == 0 => {
+                if !temp.is_empty() {
+                    values.push(temp.clone());
+                    temp.clear();
+                }
+            }
+            _ => temp.push(c),
+        }
+    }
+    if !temp.is_empty() {
+        values.push(temp);
+    }
+    values
+}
+
+fn is_number(x: &str) -> bool {
+    !x.is_empty()
+        && x.chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == '-' || c == '+')
+        && x.parse::<f64>().is_ok()
+}
+
+/// "calc(1px + 2px)" 这类函数，括号需配对
+fn is_function(x: &str, names: &[&str]) -> bool {
+    let Some((name, rest)) = x.split_once('(') else {
+        return false;
+    };
+    if !names.contains(&name) || !rest.ends_with(')') {
+        return false;
+    }
+    let mut depth = 1;
+    for c in rest.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+        if depth < 0 {
+            return false;
+        }
+    }
+    depth == 0
+}
+
+const KEYWORDS: [&str; 4] = ["inherit", "initial", "unset", "revert"];
+
+const UNITS: [&str; 22] = [
+    "px", "%", "rpx", "rem", "em", "vw", "vh", "vmin", "vmax", "svw", "svh", "dvw", "dvh", "lvw",
+    "lvh", "ch", "ex", "pt", "pc", "mm", "cm", "in",
+];
+
+fn is_length(x: &str) -> bool {
+    if is_number(x)
+        || KEYWORDS.contains(&x)
+        || ["auto", "none", "fit-content", "min-content", "max-content"].contains(&x)
+        || is_function(
+            x,
+            &["calc", "var", "env", "constant", "min", "max", "clamp"],
+        )
+    {
+        return true;
+    }
+    UNITS.iter().any(|unit| {
+        x.strip_suffix(unit)
+            .map(|n| is_number(n) && !n.ends_with('.'))
+            .unwrap_or(false)
+    })
+}
+
+fn is_color(x: &str) -> bool {
+    if let Some(hex) = x.strip_prefix('#') {
+        return [3, 4, 6, 8].contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    if is_function(
+        x,
+        &[
+            "rgb",
+            "rgba",
+            "hsl",
+            "hsla",
+            "hwb",
+            "lab",
+            "lch",
+            "oklab",
+            "oklch",
+            "color",
+            "color-mix",
+            "var",
+            "light-dark",
+        ],
+    ) {
+        return true;
+    }
+    let x = x.to_ascii_lowercase();
+    KEYWORDS.contains(&x.as_str())
+        || ["transparent", "currentcolor", "none"].contains(&x.as_str())
+        || NAMED_COLORS.contains(&x.as_str())
+}
+
+fn is_opacity(x: &str) -> bool {
+    if let Some(n) = x.strip_suffix('%') {
+        return is_number(n) && (0.0..=100.0).contains(&n.parse::<f64>().unwrap());
+    }
+    (is_number(x) && (0.0..=1.0).contains(&x.parse::<f64>().unwrap()))
+        || KEYWORDS.contains(&x)
+        || is_function(x, &["var", "calc"])
+}
+
+fn is_duration(x: &str) -> bool {
+    let n = x
+        .strip_suffix("ms")
+        .or_else(|| x.strip_suffix('s'))
+        .unwrap_or(x);
+    is_number(n) && n.parse::<f64>().unwrap() >= 0.0
+}
+
+fn is_z_index(x: &str) -> bool {
+    x == "auto" || KEYWORDS.contains(&x) || x.parse::<i32>().is_ok()
+}
+
+/// "row between center" 或 "rbc" => "Frbc"
+fn flex_way(value: &str, span: Span) -> syn::Result<String> {
+    let words = split_values(value);
+    let letters: Vec<String> = if words.len() == 1 {
+        let short = words[0].strip_prefix('f').unwrap_or(&words[0]);
+        short.chars().map(|c| c.to_string()).collect()
+    } else {
+        words
+    };
+    let hint = "expected `<direction> <justify> <align>` such as `row between center` or `rbc`; direction is row/column (r/c), justify and align are around/between/center/end/start (a/b/c/e/s)";
+    if letters.len() != 3 {
+        return Err(syn::Error::new(
+            span,
+            format!("invalid flex `{}`, {}", value, hint),
+        ));
+    }
+    let direction = match letters[0].as_str() {
+        "r" | "row" => 'r',
+        "c" | "column" => 'c',
+        other => {
+            return Err(syn::Error::new(
+                span,
+                format!("invalid flex direction `{}`, {}", other, hint),
+            ))
+        }
+    };
+    let mut name = format!("F{}", direction);
+    for x in &letters[1..] {
+        let c = match x.as_str() {
+            "a" | "around" => 'a',
+            "b" | "between" => 'b',
+            "c" | "center" => 'c',
+            "e" | "end" => 'e',
+            "s" | "start" => 's',
+            other => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("invalid flex alignment `{}`, {}", other, hint),
+                ))
+            }
+        };
+        name.push(c);
+    }
+    Ok(name)
+}
+
+const NAMED_COLORS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths() {
+        for x in [
+            "10",
+            "-1.5",
+            "10px",
+            "2rem",
+            "50%",
+            "20rpx",
+            "auto",
+            "calc(100% - 10px)",
+        ] {
+            assert!(is_length(x), "{}", x);
+        }
+        for x in ["", "1O", "10pz", "px", "10.px", "calc(1px", "abc"] {
+            assert!(!is_length(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn colors() {
+        for x in [
+            "#fff",
+            "#FFFF",
+            "#181818",
+            "#00000080",
+            "rgba(0,0,0,.5)",
+            "Red",
+            "transparent",
+        ] {
+            assert!(is_color(x), "{}", x);
+        }
+        for x in ["#ff", "#ggg", "rgb(0,0,0", "reddish", "10"] {
+            assert!(!is_color(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn opacities() {
+        for x in ["0", "0.5", "1", "50%", "inherit", "var(--o)"] {
+            assert!(is_opacity(x), "{}", x);
+        }
+        for x in ["O.5", "1.5", "-0.1", "120%", "half"] {
+            assert!(!is_opacity(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn durations() {
+        for x in ["0.3", "0.3s", "300ms", "0"] {
+            assert!(is_duration(x), "{}", x);
+        }
+        for x in ["-1s", "300sm", "fast", "s", ""] {
+            assert!(!is_duration(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn z_indexes() {
+        for x in ["10", "-1", "auto", "inherit"] {
+            assert!(is_z_index(x), "{}", x);
+        }
+        for x in ["1.5", "top", ""] {
+            assert!(!is_z_index(x), "{}", x);
+        }
+    }
+
+    #[test]
+    fn flex_ways() {
+        let flex = |x: &str| flex_way(x, Span::call_site()).ok();
+        assert_eq!(flex("row between center").as_deref(), Some("Frbc"));
+        assert_eq!(flex("rbc").as_deref(), Some("Frbc"));
+        assert_eq!(flex("fcse").as_deref(), Some("Fcse"));
+        assert_eq!(flex("column around start").as_deref(), Some("Fcas"));
+        assert_eq!(flex("rb"), None);
+        assert_eq!(flex("xbc"), None);
+        assert_eq!(flex("row middle center"), None);
+    }
+
+    #[test]
+    fn groups() {
+        let check = |x: &str, max: usize| {
+            check_groups(x, Span::call_site(), "size", max, is_length, LENGTH_HINT).is_ok()
+        };
+        assert!(check("100% 50 md:50%", 2));
+        assert!(check("md:50% @lg/card:20", 2));
+        assert!(!check("1 2 3", 2));
+        assert!(!check("10 md:", 2));
+        assert!(!check("", 2));
+    }
+}